@@ -3,4 +3,14 @@ fn main() {
     compile_error! {
         "One of `hashbrown`, `hashmap` or `btreemap` feature should be enabled"
     }
+
+    #[cfg(any(
+        all(feature = "hashbrown", feature = "hashmap"),
+        all(feature = "hashbrown", feature = "btreemap"),
+        all(feature = "hashmap", feature = "btreemap"),
+    ))]
+    compile_error! {
+        "Only one of `hashbrown`, `hashmap` or `btreemap` feature should be enabled. `hashmap` is \
+         enabled by default, use `default-features = false` to enable another one"
+    }
 }
@@ -0,0 +1,158 @@
+use {
+    crate::{BiMultiMap, DefaultHashBuilder, InnerSet, Key, LeftKeys, MapHasher, lookup::lookup},
+    std::{borrow::Borrow, iter::FusedIterator, ops::Deref},
+};
+
+/// The values connected to each other by mappings, obtained with [BiMultiMap::component_of_left],
+/// [BiMultiMap::component_of_right] or [BiMultiMap::components]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Component<'a, L, R> {
+    pub lefts:  Vec<&'a L>,
+    pub rights: Vec<&'a R>,
+}
+
+impl<L: Key, R: Key, S: MapHasher> BiMultiMap<L, R, S> {
+    /// All the values reachable from `left` by alternately following mappings from left to right
+    /// and from right to left, `left` included
+    ///
+    /// Returns [None] if `left` isn't in the map.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let devices = bimultimap::BiMultiMap::from_iter([
+    ///     ("alice", 0),
+    ///     ("bob", 0),
+    ///     ("bob", 1),
+    ///     ("carol", 2),
+    /// ]);
+    ///
+    /// let component = devices.component_of_left("alice").unwrap();
+    ///
+    /// assert_eq!(component.lefts.len(), 2);
+    /// assert_eq!(component.rights.len(), 2);
+    /// ```
+    pub fn component_of_left<Q>(&self, left: &Q) -> Option<Component<'_, L, R>>
+    where
+        L: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        let (left, _) = self.left_map_rc.get_key_value(lookup(&left))?;
+
+        Some(self.explore(
+            Component {
+                lefts:  vec![&**left],
+                rights: Vec::new(),
+            },
+            &mut InnerSet::<_, S>::from_iter([&**left]),
+            &mut InnerSet::<_, S>::default(),
+        ))
+    }
+
+    /// All the values reachable from `right` by alternately following mappings from right to left
+    /// and from left to right, `right` included
+    ///
+    /// Returns [None] if `right` isn't in the map.
+    pub fn component_of_right<Q>(&self, right: &Q) -> Option<Component<'_, L, R>>
+    where
+        R: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        let (right, _) = self.right_map_rc.get_key_value(lookup(&right))?;
+
+        Some(self.explore(
+            Component {
+                lefts:  Vec::new(),
+                rights: vec![&**right],
+            },
+            &mut InnerSet::<_, S>::default(),
+            &mut InnerSet::<_, S>::from_iter([&**right]),
+        ))
+    }
+
+    /// Iterates over all the connected components of the map, each value being in exactly one
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let devices = bimultimap::BiMultiMap::from_iter([("alice", 0), ("bob", 0), ("carol", 1)]);
+    ///
+    /// assert_eq!(devices.components().count(), 2);
+    /// ```
+    pub fn components(&self) -> Components<'_, L, R, S> {
+        Components {
+            map:         self,
+            left_values: self.left_values(),
+            seen_lefts:  InnerSet::<_, S>::default(),
+            seen_rights: InnerSet::<_, S>::default(),
+        }
+    }
+
+    /// Completes `component` with all the values connected to it, skipping the ones already seen
+    fn explore<'a>(
+        &'a self,
+        mut component: Component<'a, L, R>,
+        seen_lefts: &mut InnerSet<&'a L, S>,
+        seen_rights: &mut InnerSet<&'a R, S>,
+    ) -> Component<'a, L, R> {
+        // Both vectors are also used as the queues of a breadth-first search
+        let (mut next_left, mut next_right) = (0usize, 0usize);
+
+        while next_left < component.lefts.len() || next_right < component.rights.len() {
+            for &left in &component.lefts[next_left..] {
+                component.rights.extend(
+                    self.iter_left(left)
+                        .filter(|right| seen_rights.insert(*right)),
+                );
+            }
+            next_left = component.lefts.len();
+
+            for &right in &component.rights[next_right..] {
+                component.lefts.extend(
+                    self.iter_right(right)
+                        .filter(|left| seen_lefts.insert(*left)),
+                );
+            }
+            next_right = component.rights.len();
+        }
+
+        component
+    }
+}
+
+/// An iterator over the connected components of a [BiMultiMap], obtained with
+/// [BiMultiMap::components]
+pub struct Components<'a, L: Key, R: Key, S: MapHasher = DefaultHashBuilder> {
+    map:         &'a BiMultiMap<L, R, S>,
+    left_values: LeftKeys<'a, L, R, S>,
+    seen_lefts:  InnerSet<&'a L, S>,
+    seen_rights: InnerSet<&'a R, S>,
+}
+
+impl<'a, L: Key, R: Key, S: MapHasher> Iterator for Components<'a, L, R, S> {
+    type Item = Component<'a, L, R>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Every right value is mapped to a left value, so starting from the left ones is enough
+        let left = self
+            .left_values
+            .by_ref()
+            .map(Deref::deref)
+            .find(|left| self.seen_lefts.insert(*left))?;
+
+        Some(self.map.explore(
+            Component {
+                lefts:  vec![left],
+                rights: Vec::new(),
+            },
+            &mut self.seen_lefts,
+            &mut self.seen_rights,
+        ))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.left_values.size_hint().1)
+    }
+}
+
+impl<L: Key, R: Key, S: MapHasher> FusedIterator for Components<'_, L, R, S> {}
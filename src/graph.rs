@@ -0,0 +1,273 @@
+use {
+    crate::{
+        BiMultiMap,
+        DefaultHashBuilder,
+        InnerMap,
+        InnerSet,
+        Key,
+        MapHasher,
+        Rc,
+        lookup::lookup,
+    },
+    std::{borrow::Borrow, collections::VecDeque, iter::FusedIterator, ops::Deref},
+};
+
+/// Graph algorithms, where each (L, R) mapping is an edge from L to R
+///
+/// The reverse index kept for the right side is used to walk the edges backwards.
+impl<T: Key, S: MapHasher> BiMultiMap<T, T, S> {
+    /// Iterates over the values reachable from `start` by following mappings from left to right,
+    /// in breadth-first order
+    ///
+    /// `start` itself is only yielded if it's part of a cycle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let depends_on = bimultimap::BiMultiMap::from_iter([("app", "log"), ("log", "core")]);
+    ///
+    /// assert_eq!(
+    ///     depends_on.reachable_from_left("app").collect::<Vec<_>>(),
+    ///     vec![&"log", &"core"]
+    /// );
+    /// assert_eq!(
+    ///     depends_on.reachable_from_right("core").collect::<Vec<_>>(),
+    ///     vec![&"log", &"app"]
+    /// );
+    /// ```
+    pub fn reachable_from_left<Q>(&self, start: &Q) -> Reachable<'_, T, S>
+    where
+        T: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        Reachable::new(&self.left_map_rc, start)
+    }
+
+    /// Iterates over the values reachable from `start` by following mappings from right to left,
+    /// in breadth-first order
+    ///
+    /// `start` itself is only yielded if it's part of a cycle.
+    pub fn reachable_from_right<Q>(&self, start: &Q) -> Reachable<'_, T, S>
+    where
+        T: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        Reachable::new(&self.right_map_rc, start)
+    }
+
+    /// Maps each left value to every value reachable from it
+    ///
+    /// The values are [shared](BiMultiMap#shared-values) with `self`.
+    pub fn transitive_closure(&self) -> BiMultiMap<T, T, S> {
+        let mut closure = self.new_like();
+
+        for left in self.left_map_rc.keys() {
+            let mut reachable = self.reachable_from_left(&**left);
+
+            while let Some(right) = reachable.next_rc() {
+                closure.insert_shared(left.clone(), right.clone());
+            }
+        }
+
+        closure
+    }
+
+    /// Whether following the mappings from left to right can lead back to a value
+    pub fn has_cycle(&self) -> bool {
+        self.topological_order().is_none()
+    }
+
+    /// All the values, each left value coming before the right values it's mapped to
+    ///
+    /// Returns [None] if there is a cycle, since no such order exists.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let depends_on = bimultimap::BiMultiMap::from_iter([("app", "log"), ("log", "core")]);
+    ///
+    /// assert_eq!(
+    ///     depends_on.topological_order(),
+    ///     Some(vec![&"app", &"log", &"core"])
+    /// );
+    /// ```
+    pub fn topological_order(&self) -> Option<Vec<&T>> {
+        // The number of mappings to each value not ordered yet, which the right side already
+        // keeps track of
+        let mut in_degrees = InnerMap::<_, _, S>::default();
+        let mut ready = Vec::new();
+
+        for value in self.values() {
+            match self.right_degree(value) {
+                0 => ready.push(value),
+                in_degree => {
+                    in_degrees.insert(value, in_degree);
+                },
+            }
+        }
+
+        let mut order = Vec::with_capacity(ready.len() + in_degrees.len());
+
+        while let Some(value) = ready.pop() {
+            order.push(value);
+
+            for right in self.iter_left(value) {
+                let in_degree = in_degrees.get_mut(right)?;
+                *in_degree -= 1;
+
+                if *in_degree == 0 {
+                    in_degrees.remove(right);
+                    ready.push(right);
+                }
+            }
+        }
+
+        in_degrees.is_empty().then_some(order)
+    }
+
+    /// Groups the values such that each value of a group can reach all the others by following
+    /// mappings from left to right
+    ///
+    /// A value that isn't part of any cycle is alone in its group.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let map = bimultimap::BiMultiMap::from_iter([(0, 1), (1, 0), (1, 2)]);
+    ///
+    /// let mut components = map.strongly_connected_components();
+    /// components.iter_mut().for_each(|component| component.sort());
+    /// components.sort();
+    ///
+    /// assert_eq!(components, vec![vec![&0, &1], vec![&2]]);
+    /// ```
+    pub fn strongly_connected_components(&self) -> Vec<Vec<&T>> {
+        // Kosaraju's algorithm: the values are ordered by the end of a depth-first search along
+        // the mappings, then the reverse index is searched in the reverse order.
+        let mut visited = InnerSet::<_, S>::default();
+        let mut finished = Vec::new();
+
+        for value in self.values() {
+            if !visited.insert(value) {
+                continue;
+            }
+
+            let mut stack = vec![(value, self.iter_left(value))];
+
+            while let Some((value, rights)) = stack.last_mut() {
+                match rights.find(|right| !visited.contains(right)) {
+                    Some(right) => {
+                        visited.insert(right);
+                        stack.push((right, self.iter_left(right)));
+                    },
+                    None => {
+                        finished.push(*value);
+                        stack.pop();
+                    },
+                }
+            }
+        }
+
+        let mut assigned = InnerSet::<_, S>::default();
+        let mut components = Vec::new();
+
+        for value in finished.into_iter().rev() {
+            if !assigned.insert(value) {
+                continue;
+            }
+
+            let mut component = vec![value];
+            let mut stack = vec![value];
+
+            while let Some(value) = stack.pop() {
+                for left in self.iter_right(value) {
+                    if assigned.insert(left) {
+                        component.push(left);
+                        stack.push(left);
+                    }
+                }
+            }
+
+            components.push(component);
+        }
+
+        components
+    }
+
+    /// Every distinct value, whatever its side
+    fn values(&self) -> impl Iterator<Item = &T> {
+        self.left_map_rc.keys().map(Deref::deref).chain(
+            self.right_map_rc
+                .keys()
+                .map(Deref::deref)
+                .filter(|right| !self.contains_left(*right)),
+        )
+    }
+}
+
+/// A breadth-first iterator over the values reachable from another, obtained with
+/// [BiMultiMap::reachable_from_left] or [BiMultiMap::reachable_from_right]
+pub struct Reachable<'a, T: Key, S: MapHasher = DefaultHashBuilder> {
+    edges:   &'a InnerMap<Rc<T>, InnerSet<Rc<T>, S>, S>,
+    queue:   VecDeque<&'a Rc<T>>,
+    visited: InnerSet<&'a T, S>,
+}
+
+impl<'a, T: Key, S: MapHasher> Reachable<'a, T, S> {
+    fn new<Q>(edges: &'a InnerMap<Rc<T>, InnerSet<Rc<T>, S>, S>, start: &Q) -> Self
+    where
+        T: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        let mut reachable = Reachable {
+            edges,
+            queue: VecDeque::new(),
+            visited: InnerSet::<_, S>::default(),
+        };
+
+        if let Some(next_values) = edges.get(lookup(&start)) {
+            reachable.push_all(next_values);
+        }
+
+        reachable
+    }
+
+    fn push_all(&mut self, next_values: &'a InnerSet<Rc<T>, S>) {
+        for next in next_values {
+            if self.visited.insert(&**next) {
+                self.queue.push_back(next);
+            }
+        }
+    }
+
+    /// Same as [Iterator::next], as the [Rc]s stored in the map
+    pub(crate) fn next_rc(&mut self) -> Option<&'a Rc<T>> {
+        let value = self.queue.pop_front()?;
+
+        if let Some(next_values) = self.edges.get(value) {
+            self.push_all(next_values);
+        }
+
+        Some(value)
+    }
+}
+
+impl<'a, T: Key, S: MapHasher> Iterator for Reachable<'a, T, S> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_rc().map(Deref::deref)
+    }
+}
+
+impl<T: Key, S: MapHasher> FusedIterator for Reachable<'_, T, S> {}
+
+impl<T: Key, S: MapHasher> Clone for Reachable<'_, T, S> {
+    fn clone(&self) -> Self {
+        Reachable {
+            edges:   self.edges,
+            queue:   self.queue.clone(),
+            visited: self.visited.clone(),
+        }
+    }
+}
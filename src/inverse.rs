@@ -0,0 +1,232 @@
+use {
+    crate::{
+        BiMultiMap,
+        DefaultHashBuilder,
+        InnerSet,
+        Iter,
+        IterLeft,
+        IterRef,
+        IterRight,
+        IterSorted,
+        Key,
+        LeftKeys,
+        MapHasher,
+        Rc,
+        RightKeys,
+    },
+    std::borrow::Borrow,
+};
+
+/// A [BiMultiMap] borrowed with its sides swapped, obtained with [BiMultiMap::as_inverse]
+///
+/// It has the same read API as a [BiMultiMap], its left side being the right side of the map.
+///
+/// # Example
+///
+/// ```
+/// let map = bimultimap::BiMultiMap::from_iter([('a', 0), ('a', 1)]);
+/// let inverse = map.as_inverse();
+///
+/// assert_eq!(inverse.get_one_left(&0), Some(&'a'));
+/// assert_eq!(inverse.right_degree(&'a'), 2);
+/// assert_eq!(inverse.left_len(), 2);
+/// ```
+pub struct InverseView<'a, L: Key, R: Key, S: MapHasher = DefaultHashBuilder> {
+    map: &'a BiMultiMap<R, L, S>,
+}
+
+impl<L: Key, R: Key, S: MapHasher> Clone for InverseView<'_, L, R, S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<L: Key, R: Key, S: MapHasher> Copy for InverseView<'_, L, R, S> {}
+
+impl<'a, L: Key, R: Key, S: MapHasher> IntoIterator for InverseView<'a, L, R, S> {
+    type IntoIter = Iter<'a, L, R, S>;
+    type Item = (&'a Rc<L>, &'a Rc<R>);
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, L: Key, R: Key, S: MapHasher> InverseView<'a, L, R, S> {
+    pub(crate) fn new(map: &'a BiMultiMap<R, L, S>) -> Self {
+        InverseView { map }
+    }
+
+    /// The map, with its sides in their original order
+    pub fn as_inverse(&self) -> &'a BiMultiMap<R, L, S> {
+        self.map
+    }
+
+    /// Same as [BiMultiMap::iter]
+    pub fn iter(&self) -> Iter<'a, L, R, S> {
+        Iter::from_side(&self.map.right_map_rc, self.map.len())
+    }
+
+    /// Same as [BiMultiMap::iter_ref]
+    pub fn iter_ref(&self) -> IterRef<'a, L, R, S> {
+        IterRef::from_side(&self.map.right_map_rc, self.map.len())
+    }
+
+    /// Same as [BiMultiMap::iter_sorted]
+    pub fn iter_sorted(&self) -> IterSorted<'a, L, R>
+    where
+        L: Ord,
+        R: Ord,
+    {
+        IterSorted::new(self.iter_ref())
+    }
+
+    /// Same as [BiMultiMap::len]
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Same as [BiMultiMap::is_empty]
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Same as [BiMultiMap::contains]
+    pub fn contains<QL, QR>(&self, left: &QL, right: &QR) -> bool
+    where
+        L: Borrow<QL>,
+        R: Borrow<QR>,
+        QL: Key + ?Sized,
+        QR: Key + ?Sized,
+    {
+        self.map.contains(right, left)
+    }
+
+    /// Same as [BiMultiMap::get_left]
+    pub fn get_left<Q>(&self, left: &Q) -> Option<&'a InnerSet<Rc<R>, S>>
+    where
+        L: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        self.map.get_right(left)
+    }
+
+    /// Same as [BiMultiMap::get_right]
+    pub fn get_right<Q>(&self, right: &Q) -> Option<&'a InnerSet<Rc<L>, S>>
+    where
+        R: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        self.map.get_left(right)
+    }
+
+    /// Same as [BiMultiMap::get_one_left]
+    pub fn get_one_left<Q>(&self, left: &Q) -> Option<&'a R>
+    where
+        L: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        self.map.get_one_right(left)
+    }
+
+    /// Same as [BiMultiMap::get_one_right]
+    pub fn get_one_right<Q>(&self, right: &Q) -> Option<&'a L>
+    where
+        R: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        self.map.get_one_left(right)
+    }
+
+    /// Same as [BiMultiMap::get_left_vec]
+    pub fn get_left_vec<Q>(&self, left: &Q) -> Option<Vec<&'a R>>
+    where
+        L: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        self.map.get_right_vec(left)
+    }
+
+    /// Same as [BiMultiMap::get_right_vec]
+    pub fn get_right_vec<Q>(&self, right: &Q) -> Option<Vec<&'a L>>
+    where
+        R: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        self.map.get_left_vec(right)
+    }
+
+    /// Same as [BiMultiMap::iter_left]
+    pub fn iter_left<Q>(&self, left: &Q) -> IterRight<'a, R>
+    where
+        L: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        self.map.iter_right(left)
+    }
+
+    /// Same as [BiMultiMap::iter_right]
+    pub fn iter_right<Q>(&self, right: &Q) -> IterLeft<'a, L>
+    where
+        R: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        self.map.iter_left(right)
+    }
+
+    /// Same as [BiMultiMap::contains_left]
+    pub fn contains_left<Q>(&self, left: &Q) -> bool
+    where
+        L: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        self.map.contains_right(left)
+    }
+
+    /// Same as [BiMultiMap::contains_right]
+    pub fn contains_right<Q>(&self, right: &Q) -> bool
+    where
+        R: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        self.map.contains_left(right)
+    }
+
+    /// Same as [BiMultiMap::left_degree]
+    pub fn left_degree<Q>(&self, left: &Q) -> usize
+    where
+        L: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        self.map.right_degree(left)
+    }
+
+    /// Same as [BiMultiMap::right_degree]
+    pub fn right_degree<Q>(&self, right: &Q) -> usize
+    where
+        R: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        self.map.left_degree(right)
+    }
+
+    /// Same as [BiMultiMap::left_len]
+    pub fn left_len(&self) -> usize {
+        self.map.right_len()
+    }
+
+    /// Same as [BiMultiMap::right_len]
+    pub fn right_len(&self) -> usize {
+        self.map.left_len()
+    }
+
+    /// Same as [BiMultiMap::left_values]
+    pub fn left_values(&self) -> RightKeys<'a, L, R, S> {
+        self.map.right_values()
+    }
+
+    /// Same as [BiMultiMap::right_values]
+    pub fn right_values(&self) -> LeftKeys<'a, R, L, S> {
+        self.map.left_values()
+    }
+}
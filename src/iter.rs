@@ -0,0 +1,332 @@
+use {
+    crate::{
+        BiMultiMap,
+        DefaultHashBuilder,
+        InnerMap,
+        InnerSet,
+        Key,
+        MapHasher,
+        MapIntoIter,
+        MapIter,
+        Rc,
+        SetIntoIter,
+        SetIter,
+    },
+    std::{iter::FusedIterator, ops::Deref, vec},
+};
+
+/// An iterator over all the mappings of a [BiMultiMap], obtained with [BiMultiMap::iter]
+pub struct Iter<'a, L, R, S = DefaultHashBuilder> {
+    left_map: MapIter<'a, Rc<L>, InnerSet<Rc<R>, S>>,
+    current:  Option<(&'a Rc<L>, SetIter<'a, Rc<R>>)>,
+    len:      usize,
+}
+
+impl<'a, L: Key, R: Key, S: MapHasher> Iter<'a, L, R, S> {
+    pub(crate) fn new(map: &'a BiMultiMap<L, R, S>) -> Self {
+        Iter::from_side(&map.left_map_rc, map.len())
+    }
+
+    /// Iterates over the mappings of a single side, which is the right one for an
+    /// [InverseView](crate::InverseView)
+    pub(crate) fn from_side(side: &'a InnerMap<Rc<L>, InnerSet<Rc<R>, S>, S>, len: usize) -> Self {
+        Iter {
+            left_map: side.iter(),
+            current: None,
+            len,
+        }
+    }
+}
+
+impl<'a, L, R, S> Iterator for Iter<'a, L, R, S> {
+    type Item = (&'a Rc<L>, &'a Rc<R>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((left, right_values)) = &mut self.current &&
+                let Some(right) = right_values.next()
+            {
+                self.len -= 1;
+                return Some((*left, right));
+            }
+
+            let (left, right_set) = self.left_map.next()?;
+            self.current = Some((left, right_set.iter()));
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<L, R, S> ExactSizeIterator for Iter<'_, L, R, S> {}
+
+impl<L, R, S> FusedIterator for Iter<'_, L, R, S> {}
+
+impl<L, R, S> Clone for Iter<'_, L, R, S> {
+    fn clone(&self) -> Self {
+        Iter {
+            left_map: self.left_map.clone(),
+            current:  self.current.clone(),
+            len:      self.len,
+        }
+    }
+}
+
+/// An iterator over references to all the mappings of a [BiMultiMap], obtained with
+/// [BiMultiMap::iter_ref]
+pub struct IterRef<'a, L, R, S = DefaultHashBuilder> {
+    iter: Iter<'a, L, R, S>,
+}
+
+impl<'a, L: Key, R: Key, S: MapHasher> IterRef<'a, L, R, S> {
+    pub(crate) fn new(map: &'a BiMultiMap<L, R, S>) -> Self {
+        IterRef::from_side(&map.left_map_rc, map.len())
+    }
+
+    /// Same as [Iter::from_side]
+    pub(crate) fn from_side(side: &'a InnerMap<Rc<L>, InnerSet<Rc<R>, S>, S>, len: usize) -> Self {
+        IterRef {
+            iter: Iter::from_side(side, len),
+        }
+    }
+}
+
+impl<'a, L, R, S> Iterator for IterRef<'a, L, R, S> {
+    type Item = (&'a L, &'a R);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|(left, right)| (left.deref(), right.deref()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<L, R, S> ExactSizeIterator for IterRef<'_, L, R, S> {}
+
+impl<L, R, S> FusedIterator for IterRef<'_, L, R, S> {}
+
+impl<L, R, S> Clone for IterRef<'_, L, R, S> {
+    fn clone(&self) -> Self {
+        IterRef {
+            iter: self.iter.clone(),
+        }
+    }
+}
+
+/// The right keys of a left key in a [BiMultiMapWithValues](crate::BiMultiMapWithValues), with
+/// their values
+type RightValues<'a, R, V> = MapIter<'a, Rc<R>, V>;
+
+/// An iterator over all the mappings of a [BiMultiMapWithValues](crate::BiMultiMapWithValues)
+/// and their values, obtained with
+/// [BiMultiMapWithValues::iter_edges](crate::BiMultiMapWithValues::iter_edges)
+pub struct IterEdges<'a, L, R, V, S = DefaultHashBuilder> {
+    values:  MapIter<'a, Rc<L>, InnerMap<Rc<R>, V, S>>,
+    current: Option<(&'a Rc<L>, RightValues<'a, R, V>)>,
+    len:     usize,
+}
+
+impl<'a, L: Key, R: Key, V, S: MapHasher> IterEdges<'a, L, R, V, S> {
+    pub(crate) fn new(values: &'a InnerMap<Rc<L>, InnerMap<Rc<R>, V, S>, S>, len: usize) -> Self {
+        IterEdges {
+            values: values.iter(),
+            current: None,
+            len,
+        }
+    }
+}
+
+impl<'a, L, R, V, S> Iterator for IterEdges<'a, L, R, V, S> {
+    type Item = (&'a L, &'a R, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((left, values)) = &mut self.current &&
+                let Some((right, value)) = values.next()
+            {
+                self.len -= 1;
+                return Some((left.deref(), right.deref(), value));
+            }
+
+            let (left, values) = self.values.next()?;
+            self.current = Some((left, values.iter()));
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<L, R, V, S> ExactSizeIterator for IterEdges<'_, L, R, V, S> {}
+
+impl<L, R, V, S> FusedIterator for IterEdges<'_, L, R, V, S> {}
+
+impl<L, R, V, S> Clone for IterEdges<'_, L, R, V, S> {
+    fn clone(&self) -> Self {
+        IterEdges {
+            values:  self.values.clone(),
+            current: self.current.clone(),
+            len:     self.len,
+        }
+    }
+}
+
+/// An iterator over references to all the mappings of a [BiMultiMap] in ascending order,
+/// obtained with [BiMultiMap::iter_sorted]
+///
+/// The mappings are sorted by left value, then by right value.
+pub struct IterSorted<'a, L, R> {
+    pairs: vec::IntoIter<(&'a L, &'a R)>,
+}
+
+impl<'a, L: Ord, R: Ord> IterSorted<'a, L, R> {
+    pub(crate) fn new(pairs: impl Iterator<Item = (&'a L, &'a R)>) -> Self {
+        let mut pairs = pairs.collect::<Vec<_>>();
+
+        // Mappings are unique, so a stable sort wouldn't order anything differently
+        pairs.sort_unstable();
+
+        IterSorted {
+            pairs: pairs.into_iter(),
+        }
+    }
+}
+
+impl<'a, L, R> Iterator for IterSorted<'a, L, R> {
+    type Item = (&'a L, &'a R);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.pairs.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.pairs.size_hint()
+    }
+}
+
+impl<L, R> DoubleEndedIterator for IterSorted<'_, L, R> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.pairs.next_back()
+    }
+}
+
+impl<L, R> ExactSizeIterator for IterSorted<'_, L, R> {}
+
+impl<L, R> FusedIterator for IterSorted<'_, L, R> {}
+
+impl<L, R> Clone for IterSorted<'_, L, R> {
+    fn clone(&self) -> Self {
+        IterSorted {
+            pairs: self.pairs.clone(),
+        }
+    }
+}
+
+/// An owning iterator over all the mappings of a [BiMultiMap]
+///
+/// Unlike [Iter], it isn't [Clone], since the inner maps' owning iterators aren't.
+pub struct IntoIter<L, R, S = DefaultHashBuilder> {
+    left_map: MapIntoIter<Rc<L>, InnerSet<Rc<R>, S>>,
+    current:  Option<(Rc<L>, SetIntoIter<Rc<R>>)>,
+    len:      usize,
+}
+
+impl<L: Key, R: Key, S: MapHasher> IntoIter<L, R, S> {
+    pub(crate) fn new(map: BiMultiMap<L, R, S>) -> Self {
+        IntoIter {
+            len:      map.len(),
+            left_map: map.left_map_rc.into_iter(),
+            current:  None,
+        }
+    }
+}
+
+impl<L, R, S> Iterator for IntoIter<L, R, S> {
+    type Item = (Rc<L>, Rc<R>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((left, right_values)) = &mut self.current &&
+                let Some(right) = right_values.next()
+            {
+                self.len -= 1;
+                return Some((left.clone(), right));
+            }
+
+            let (left, right_set) = self.left_map.next()?;
+            self.current = Some((left, right_set.into_iter()));
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<L, R, S> ExactSizeIterator for IntoIter<L, R, S> {}
+
+impl<L, R, S> FusedIterator for IntoIter<L, R, S> {}
+
+/// An owning iterator over all the mappings of a [BiMultiMap], obtained with
+/// [BiMultiMap::into_pairs]
+///
+/// A value is only cloned when it's part of several mappings, the last one getting the owned
+/// value, or when it's shared with a clone of the map.
+pub struct IntoPairs<L, R, S = DefaultHashBuilder> {
+    left_map: MapIntoIter<Rc<L>, InnerSet<Rc<R>, S>>,
+    current:  Option<(L, SetIntoIter<Rc<R>>)>,
+    len:      usize,
+}
+
+impl<L: Key, R: Key, S: MapHasher> IntoPairs<L, R, S> {
+    pub(crate) fn new(map: BiMultiMap<L, R, S>) -> Self {
+        let len = map.len();
+
+        IntoPairs {
+            left_map: map.into_left_map_rc().into_iter(),
+            current: None,
+            len,
+        }
+    }
+}
+
+impl<L: Clone, R: Clone, S> Iterator for IntoPairs<L, R, S> {
+    type Item = (L, R);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((_, right_values)) = &mut self.current &&
+                let Some(right) = right_values.next()
+            {
+                self.len -= 1;
+
+                let is_last = right_values.len() == 0;
+                let left = if is_last {
+                    self.current.take().map(|(left, _)| left)?
+                } else {
+                    self.current.as_ref().map(|(left, _)| left.clone())?
+                };
+
+                return Some((left, Rc::unwrap_or_clone(right)));
+            }
+
+            let (left, right_set) = self.left_map.next()?;
+            self.current = Some((Rc::unwrap_or_clone(left), right_set.into_iter()));
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<L: Clone, R: Clone, S> ExactSizeIterator for IntoPairs<L, R, S> {}
+
+impl<L: Clone, R: Clone, S> FusedIterator for IntoPairs<L, R, S> {}
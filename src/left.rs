@@ -1,39 +1,129 @@
 use {
-    crate::{BiMultiMap, HashSet, Rc},
-    std::{borrow::Borrow, collections::hash_map::Keys, hash::Hash, ops::Deref},
+    crate::{
+        BiMultiMap,
+        DefaultHashBuilder,
+        InnerMap,
+        InnerSet,
+        Key,
+        Keys,
+        MapHasher,
+        Rc,
+        SetDiff,
+        SetIter,
+        intern,
+        intern_rc,
+        lookup::lookup,
+    },
+    std::{borrow::Borrow, iter::FusedIterator, ops::Deref},
 };
 
-impl<LeftType: Hash + Eq, RightType: Hash + Eq> BiMultiMap<LeftType, RightType> {
-    pub fn get_left(&self, left: &LeftType) -> Option<&HashSet<Rc<RightType>>> {
-        self.left_map_rc.get(left)
+impl<LeftType: Key, RightType: Key, S: MapHasher> BiMultiMap<LeftType, RightType, S> {
+    /// Gets the right values of a left key
+    ///
+    /// The key may be any borrowed form of `LeftType`, like with
+    /// [HashMap::get](std::collections::HashMap::get).
+    pub fn get_left<Q>(&self, left: &Q) -> Option<&InnerSet<Rc<RightType>, S>>
+    where
+        LeftType: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        self.left_map_rc.get(lookup(&left))
     }
 
-    pub fn get_one_left(&self, left: &LeftType) -> Option<&RightType> {
+    pub fn get_one_left<Q>(&self, left: &Q) -> Option<&RightType>
+    where
+        LeftType: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
         self.get_left(left)
             .and_then(|e| e.iter().next())
             .map(Deref::deref)
     }
 
-    pub(crate) fn get_mut_left(&mut self, left: &LeftType) -> Option<&mut HashSet<Rc<RightType>>> {
-        self.left_map_rc.get_mut(left)
+    /// Whether the left key is mapped to any right value
+    pub fn contains_left<Q>(&self, left: &Q) -> bool
+    where
+        LeftType: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        self.left_map_rc.contains_key(lookup(&left))
     }
 
-    pub fn get_left_vec(&self, left: &LeftType) -> Option<Vec<&RightType>> {
-        self.left_map_rc
-            .get(left)
+    /// The number of right values mapped to the left key
+    pub fn left_degree<Q>(&self, left: &Q) -> usize
+    where
+        LeftType: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        self.get_left(left).map_or(0, |right_set| right_set.len())
+    }
+
+    /// The number of distinct left keys
+    pub fn left_len(&self) -> usize {
+        self.left_map_rc.len()
+    }
+
+    /// The number of left keys the map can hold without reallocating
+    #[cfg(any(feature = "hashmap", feature = "hashbrown"))]
+    pub fn left_capacity(&self) -> usize {
+        self.left_map_rc.capacity()
+    }
+
+    /// Reserves room for at least `additional` more left keys
+    #[cfg(any(feature = "hashmap", feature = "hashbrown"))]
+    pub fn reserve_left(&mut self, additional: usize) {
+        self.left_map_rc.reserve(additional);
+    }
+
+    pub(crate) fn get_mut_left<Q>(&mut self, left: &Q) -> Option<&mut InnerSet<Rc<RightType>, S>>
+    where
+        LeftType: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        self.left_map_rc.get_mut(lookup(&left))
+    }
+
+    pub fn get_left_vec<Q>(&self, left: &Q) -> Option<Vec<&RightType>>
+    where
+        LeftType: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        self.get_left(left)
             .map(|map| map.iter().map(Deref::deref).collect())
     }
 
-    pub fn remove_left<LeftRef: Borrow<LeftType>>(
-        &mut self,
-        left: LeftRef,
-    ) -> Option<HashSet<Rc<RightType>>> {
-        let left = left.borrow();
-        match self.left_map_rc.remove(left) {
+    /// Iterates over the right values of a left key, without allocating
+    ///
+    /// The iterator is empty if the key isn't in the map.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let map = bimultimap::BiMultiMap::from_iter([(0, 0), (0, 1), (1, 0)]);
+    ///
+    /// assert_eq!(map.iter_left(&0).len(), 2);
+    /// assert_eq!(map.iter_left(&2).next(), None);
+    /// ```
+    pub fn iter_left<Q>(&self, left: &Q) -> IterLeft<'_, RightType>
+    where
+        LeftType: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        IterLeft {
+            right_values: self.get_left(left).map(|right_set| right_set.iter()),
+        }
+    }
+
+    pub fn remove_left<Q>(&mut self, left: &Q) -> Option<InnerSet<Rc<RightType>, S>>
+    where
+        LeftType: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        match self.left_map_rc.remove(lookup(&left)) {
             Some(right_set) => {
                 right_set.iter().for_each(|right| {
-                    let is_empty = self.get_mut_right(right).map(|hashet_left| {
-                        hashet_left.remove(left);
+                    let is_empty = self.get_mut_right(&**right).map(|hashet_left| {
+                        hashet_left.remove(lookup(&left));
 
                         hashet_left.is_empty()
                     });
@@ -51,12 +141,97 @@ impl<LeftType: Hash + Eq, RightType: Hash + Eq> BiMultiMap<LeftType, RightType>
         }
     }
 
+    /// Maps the left key to all of `right_values`
+    ///
+    /// Unlike calling [BiMultiMap::insert] for each value, the left key is only looked up once.
+    ///
+    /// Returns the number of mappings added.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut map = bimultimap::BiMultiMap::from_iter([(0, 0)]);
+    ///
+    /// assert_eq!(map.extend_left(0, [0, 1, 1]), 1);
+    /// assert_eq!(map.len(), 2);
+    /// ```
+    pub fn extend_left<I: IntoIterator<Item = RightType>>(
+        &mut self,
+        left: LeftType,
+        right_values: I,
+    ) -> usize {
+        let left_rc = intern(&self.left_map_rc, left);
+        let right_set = self.left_map_rc.entry(left_rc.clone()).or_default();
+        let mut added = 0usize;
+
+        for right in right_values {
+            let right_rc = intern(&self.right_map_rc, right);
+
+            if right_set.insert(right_rc.clone()) {
+                self.right_map_rc
+                    .entry(right_rc)
+                    .or_default()
+                    .insert(left_rc.clone());
+
+                added += 1;
+            }
+        }
+
+        // Nothing to map the key to, so it mustn't stay with an empty set
+        if right_set.is_empty() {
+            self.left_map_rc.remove(&left_rc);
+        }
+
+        self.len += added;
+
+        added
+    }
+
+    /// Only keeps the left keys for which `f` returns `true`, given their right values
+    pub fn retain_left<F: FnMut(&LeftType, &InnerSet<Rc<RightType>, S>) -> bool>(
+        &mut self,
+        mut f: F,
+    ) {
+        let mut removed = Vec::new();
+
+        self.left_map_rc.retain(|left, right_set| {
+            let should_keep = f(left, right_set);
+
+            if !should_keep {
+                removed.push((left.clone(), std::mem::take(right_set)));
+            }
+
+            should_keep
+        });
+
+        for (left, right_set) in removed {
+            self.len -= right_set.len();
+
+            for right in right_set {
+                let should_remove_right = self.right_map_rc.get_mut(&right).map(|left_set| {
+                    left_set.remove(&left);
+                    left_set.is_empty()
+                });
+
+                if should_remove_right == Some(true) {
+                    self.right_map_rc.remove(&right);
+                }
+            }
+        }
+    }
+
     // Setter
     /// Set a right_value by the left key
     ///
     /// The argument passed is an [Rc] for the `left_key`, since it might be inserted as a value
     /// for all the right_values that are in `right_values` but not present in the [`BiMultiMap`]
-    pub fn set_left(&mut self, left_key: Rc<LeftType>, right_values: HashSet<RightType>) {
+    ///
+    /// Returns the right values that were added and removed for this key.
+    pub fn set_left(
+        &mut self,
+        left_key: Rc<LeftType>,
+        right_values: InnerSet<RightType, S>,
+    ) -> SetDiff<RightType, S> {
         // These 2 declarations, demonstrate perfectly what this function does.
         //
         // We have on one side what Ill call `bmm_right_values` (BiMultiMapRightValues) and
@@ -69,10 +244,12 @@ impl<LeftType: Hash + Eq, RightType: Hash + Eq> BiMultiMap<LeftType, RightType>
         //    in both. No need to modify anything.
         //  3. If a value of `right_values` is not in `bmm_right_values` => We want to add it to
         //     `bmm_right_values`. This is `to_add_as_value_of_right`.
-        let mut to_add_as_value_of_right = HashSet::new();
-        let mut to_be_removed = HashSet::new();
+        let mut to_add_as_value_of_right = InnerSet::<_, S>::default();
+        let mut to_be_removed = InnerSet::<_, S>::default();
 
-        match self.get_mut_left(&left_key) {
+        let left_key = intern_rc(&self.left_map_rc, left_key);
+
+        match self.left_map_rc.get_mut(&left_key) {
             Some(right_set) => {
                 // Check for value to remove
                 for right_value in right_set.iter() {
@@ -85,33 +262,41 @@ impl<LeftType: Hash + Eq, RightType: Hash + Eq> BiMultiMap<LeftType, RightType>
                 // Check for value to add
                 for right_value in right_values {
                     if !right_set.contains(&right_value) {
-                        let right_rc = Rc::new(right_value);
+                        let right_rc = intern(&self.right_map_rc, right_value);
                         to_add_as_value_of_right.insert(right_rc.clone());
                         right_set.insert(right_rc);
                     }
                 }
             },
+            // Nothing to map the key to, so it mustn't be inserted with an empty set
+            None if right_values.is_empty() => {},
             None => {
-                let rc_values: HashSet<_> = right_values.into_iter().map(Rc::new).collect();
-                // Ok to clone since it's an `HashSet` of `Rc`
+                let rc_values: InnerSet<_, S> = right_values
+                    .into_iter()
+                    .map(|right_value| intern(&self.right_map_rc, right_value))
+                    .collect();
+                // Ok to clone since it's a `Set` of `Rc`
                 let rc_values_cloned = rc_values.clone();
                 self.left_map_rc.insert(left_key.clone(), rc_values);
                 to_add_as_value_of_right.extend(rc_values_cloned);
             },
         }
 
-        for right_key in to_add_as_value_of_right {
+        self.len += to_add_as_value_of_right.len();
+        self.len -= to_be_removed.len();
+
+        for right_key in to_add_as_value_of_right.iter() {
             self.right_map_rc
                 .entry(right_key.clone())
                 .and_modify(|left_set| {
                     left_set.insert(left_key.clone());
                 })
-                .or_insert_with(|| HashSet::from_iter([left_key.clone()]));
+                .or_insert_with(|| InnerSet::<_, S>::from_iter([left_key.clone()]));
         }
 
-        for right_key in to_be_removed {
-            if let Some(right_set_mut) = self.get_mut_left(&left_key) {
-                right_set_mut.remove(&*right_key);
+        for right_key in to_be_removed.iter() {
+            if let Some(right_set_mut) = self.get_mut_left(&*left_key) {
+                right_set_mut.remove(&**right_key);
             }
 
             self.right_map_rc
@@ -122,23 +307,354 @@ impl<LeftType: Hash + Eq, RightType: Hash + Eq> BiMultiMap<LeftType, RightType>
 
             if self
                 .right_map_rc
-                .get(&right_key)
+                .get(right_key)
                 .is_some_and(|left_set| left_set.is_empty())
             {
-                self.right_map_rc.remove(&right_key);
+                self.right_map_rc.remove(right_key);
             }
         }
+
+        if self
+            .get_left(&*left_key)
+            .is_some_and(|right_set| right_set.is_empty())
+        {
+            self.left_map_rc.remove(&left_key);
+        }
+
+        SetDiff {
+            added:   to_add_as_value_of_right,
+            removed: to_be_removed,
+        }
+    }
+
+    /// Iterates over all the distinct left keys
+    pub fn left_values(&self) -> LeftKeys<'_, LeftType, RightType, S> {
+        LeftKeys {
+            keys: self.left_map_rc.keys(),
+        }
+    }
+
+    /// Consumes the map into a map from each left key to its right values
+    ///
+    /// Values are moved out of their [Rc] when possible: right values are only cloned when they
+    /// are mapped to several left keys, and keys only when they are shared with a clone of the
+    /// map.
+    pub fn into_left_map(self) -> InnerMap<LeftType, InnerSet<RightType, S>, S>
+    where
+        LeftType: Clone,
+        RightType: Clone,
+    {
+        self.into_left_map_rc()
+            .into_iter()
+            .map(|(left, right_set)| {
+                (
+                    Rc::unwrap_or_clone(left),
+                    right_set.into_iter().map(Rc::unwrap_or_clone).collect(),
+                )
+            })
+            .collect()
+    }
+
+    /// Drops the right map, so that the left map holds the only [Rc] of each left key, unless the
+    /// map was cloned
+    pub(crate) fn into_left_map_rc(self) -> InnerMap<Rc<LeftType>, InnerSet<Rc<RightType>, S>, S> {
+        self.left_map_rc
+    }
+
+    /// Gets the entry of a left key, to inspect or modify its right values in place
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut map = bimultimap::BiMultiMap::new();
+    ///
+    /// map.left_entry('a').insert_value(0).insert_value(1);
+    /// map.left_entry('a').and_modify(|entry| {
+    ///     entry.remove_value(&0);
+    /// });
+    ///
+    /// assert_eq!(map.get_left_vec(&'a'), Some(vec![&1]));
+    /// assert_eq!(map.get_right_vec(&0), None);
+    /// assert_eq!(map.len(), 1);
+    /// ```
+    pub fn left_entry(&mut self, left: LeftType) -> LeftEntry<'_, LeftType, RightType, S> {
+        match self
+            .left_map_rc
+            .get_key_value(&left)
+            .map(|(left_rc, _)| left_rc.clone())
+        {
+            Some(key) => LeftEntry::Occupied(OccupiedLeftEntry { map: self, key }),
+            None => {
+                LeftEntry::Vacant(VacantLeftEntry {
+                    map: self,
+                    key: left,
+                })
+            },
+        }
     }
+}
+
+/// An iterator over the right values of a left key, obtained with [BiMultiMap::iter_left]
+pub struct IterLeft<'a, RightType> {
+    right_values: Option<SetIter<'a, Rc<RightType>>>,
+}
+
+impl<'a, RightType> Iterator for IterLeft<'a, RightType> {
+    type Item = &'a RightType;
 
-    pub fn left_values(&self) -> Keys<Rc<LeftType>, HashSet<Rc<RightType>>> {
-        self.left_map_rc.keys()
+    fn next(&mut self) -> Option<Self::Item> {
+        self.right_values.as_mut()?.next().map(Deref::deref)
     }
 
-    // pub fn left_entry(
-    //     &mut self,
-    //     left_entry: Rc<LeftType>,
-    // ) -> std::collections::hash_map::Entry<'_, std::rc::Rc<LeftType>, HashSet<std::rc::Rc<RightType>>>
-    // {
-    //     self.left_map_rc.entry(left_entry)
-    // }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.right_values
+            .as_ref()
+            .map_or((0, Some(0)), Iterator::size_hint)
+    }
+}
+
+impl<RightType> ExactSizeIterator for IterLeft<'_, RightType> {}
+
+impl<RightType> FusedIterator for IterLeft<'_, RightType> {}
+
+impl<RightType> Clone for IterLeft<'_, RightType> {
+    fn clone(&self) -> Self {
+        IterLeft {
+            right_values: self.right_values.clone(),
+        }
+    }
+}
+
+/// An iterator over the left keys of a [BiMultiMap], obtained with [BiMultiMap::left_values]
+pub struct LeftKeys<'a, LeftType, RightType: 'a, S = DefaultHashBuilder> {
+    keys: Keys<'a, Rc<LeftType>, InnerSet<Rc<RightType>, S>>,
+}
+
+impl<'a, LeftType, RightType, S> Iterator for LeftKeys<'a, LeftType, RightType, S> {
+    type Item = &'a Rc<LeftType>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.keys.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.keys.size_hint()
+    }
+}
+
+impl<LeftType, RightType, S> ExactSizeIterator for LeftKeys<'_, LeftType, RightType, S> {}
+
+impl<LeftType, RightType, S> FusedIterator for LeftKeys<'_, LeftType, RightType, S> {}
+
+impl<LeftType, RightType, S> Clone for LeftKeys<'_, LeftType, RightType, S> {
+    fn clone(&self) -> Self {
+        LeftKeys {
+            keys: self.keys.clone(),
+        }
+    }
+}
+
+/// A view into a single left key of a [BiMultiMap], obtained with [BiMultiMap::left_entry]
+pub enum LeftEntry<'a, LeftType: Key, RightType: Key, S: MapHasher = DefaultHashBuilder> {
+    Occupied(OccupiedLeftEntry<'a, LeftType, RightType, S>),
+    Vacant(VacantLeftEntry<'a, LeftType, RightType, S>),
+}
+
+impl<'a, LeftType: Key, RightType: Key, S: MapHasher> LeftEntry<'a, LeftType, RightType, S> {
+    pub fn key(&self) -> &LeftType {
+        match self {
+            LeftEntry::Occupied(entry) => entry.key(),
+            LeftEntry::Vacant(entry) => entry.key(),
+        }
+    }
+
+    /// If the key is vacant, maps it to all the right values returned by `default`
+    ///
+    /// The entry stays vacant if `default` returns an empty set.
+    pub fn or_insert_with_set<F: FnOnce() -> InnerSet<RightType, S>>(self, default: F) -> Self {
+        match self {
+            LeftEntry::Occupied(entry) => LeftEntry::Occupied(entry),
+            LeftEntry::Vacant(entry) => {
+                let right_values = default();
+
+                if right_values.is_empty() {
+                    LeftEntry::Vacant(entry)
+                } else {
+                    LeftEntry::Occupied(entry.insert_value_set(right_values))
+                }
+            },
+        }
+    }
+
+    /// Maps the key to `right`, whether the entry is occupied or not
+    pub fn insert_value(self, right: RightType) -> OccupiedLeftEntry<'a, LeftType, RightType, S> {
+        match self {
+            LeftEntry::Occupied(mut entry) => {
+                entry.insert_value(right);
+                entry
+            },
+            LeftEntry::Vacant(entry) => entry.insert_value(right),
+        }
+    }
+
+    /// Calls `f` on the entry if it's occupied
+    pub fn and_modify<F: FnOnce(&mut OccupiedLeftEntry<'a, LeftType, RightType, S>)>(
+        self,
+        f: F,
+    ) -> Self {
+        match self {
+            LeftEntry::Occupied(mut entry) => {
+                f(&mut entry);
+                LeftEntry::Occupied(entry)
+            },
+            LeftEntry::Vacant(entry) => LeftEntry::Vacant(entry),
+        }
+    }
+
+    /// Removes the key and all its mappings, returning its right values if it was occupied
+    pub fn remove_all(self) -> Option<InnerSet<Rc<RightType>, S>> {
+        match self {
+            LeftEntry::Occupied(entry) => Some(entry.remove_all()),
+            LeftEntry::Vacant(_) => None,
+        }
+    }
+}
+
+/// A left key present in a [BiMultiMap]
+///
+/// If all its right values are removed, the key is removed from the map when the entry is
+/// dropped.
+pub struct OccupiedLeftEntry<'a, LeftType: Key, RightType: Key, S: MapHasher = DefaultHashBuilder> {
+    map: &'a mut BiMultiMap<LeftType, RightType, S>,
+    key: Rc<LeftType>,
+}
+
+impl<LeftType: Key, RightType: Key, S: MapHasher> OccupiedLeftEntry<'_, LeftType, RightType, S> {
+    pub fn key(&self) -> &LeftType {
+        &self.key
+    }
+
+    /// The right values currently mapped to the key
+    pub fn get(&self) -> &InnerSet<Rc<RightType>, S> {
+        &self.map.left_map_rc[&self.key]
+    }
+
+    /// Maps the key to `right`
+    ///
+    /// Returns whether the mapping was added.
+    pub fn insert_value(&mut self, right: RightType) -> bool {
+        let right_rc = intern(&self.map.right_map_rc, right);
+
+        let was_added = self
+            .map
+            .left_map_rc
+            .get_mut(&self.key)
+            .is_some_and(|right_set| right_set.insert(right_rc.clone()));
+
+        if was_added {
+            self.map
+                .right_map_rc
+                .entry(right_rc)
+                .and_modify(|left_set| {
+                    left_set.insert(self.key.clone());
+                })
+                .or_insert_with(|| InnerSet::<_, S>::from_iter([self.key.clone()]));
+
+            self.map.len += 1;
+        }
+
+        was_added
+    }
+
+    /// Removes the mapping between the key and `right`
+    ///
+    /// Returns whether the mapping was removed.
+    pub fn remove_value<Q>(&mut self, right: &Q) -> bool
+    where
+        RightType: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        let was_removed = self
+            .map
+            .left_map_rc
+            .get_mut(&self.key)
+            .is_some_and(|right_set| right_set.remove(lookup(&right)));
+
+        if was_removed {
+            let should_remove_right = self.map.get_mut_right(right).map(|left_set| {
+                left_set.remove(&self.key);
+                left_set.is_empty()
+            });
+
+            if should_remove_right == Some(true) {
+                self.map.right_map_rc.remove(lookup(&right));
+            }
+
+            self.map.len -= 1;
+        }
+
+        was_removed
+    }
+
+    /// Removes the key and all its mappings, returning its right values
+    pub fn remove_all(self) -> InnerSet<Rc<RightType>, S> {
+        self.map.remove_left(&*self.key).unwrap_or_default()
+    }
+}
+
+impl<LeftType: Key, RightType: Key, S: MapHasher> Drop
+    for OccupiedLeftEntry<'_, LeftType, RightType, S>
+{
+    fn drop(&mut self) {
+        if self
+            .map
+            .left_map_rc
+            .get(&self.key)
+            .is_some_and(|right_set| right_set.is_empty())
+        {
+            self.map.left_map_rc.remove(&self.key);
+        }
+    }
+}
+
+/// A left key absent from a [BiMultiMap]
+pub struct VacantLeftEntry<'a, LeftType: Key, RightType: Key, S: MapHasher = DefaultHashBuilder> {
+    map: &'a mut BiMultiMap<LeftType, RightType, S>,
+    key: LeftType,
+}
+
+impl<'a, LeftType: Key, RightType: Key, S: MapHasher> VacantLeftEntry<'a, LeftType, RightType, S> {
+    pub fn key(&self) -> &LeftType {
+        &self.key
+    }
+
+    pub fn into_key(self) -> LeftType {
+        self.key
+    }
+
+    /// Maps the key to `right`
+    pub fn insert_value(self, right: RightType) -> OccupiedLeftEntry<'a, LeftType, RightType, S> {
+        let mut entry = self.insert_value_set(InnerSet::<_, S>::default());
+        entry.insert_value(right);
+        entry
+    }
+
+    fn insert_value_set(
+        self,
+        right_values: InnerSet<RightType, S>,
+    ) -> OccupiedLeftEntry<'a, LeftType, RightType, S> {
+        let key = Rc::new(self.key);
+
+        self.map
+            .left_map_rc
+            .insert(key.clone(), InnerSet::<_, S>::default());
+
+        let mut entry = OccupiedLeftEntry { map: self.map, key };
+
+        for right in right_values {
+            entry.insert_value(right);
+        }
+
+        entry
+    }
 }
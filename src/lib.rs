@@ -1,20 +1,82 @@
-#![feature(coroutines)]
-#![feature(gen_blocks)]
-#![feature(impl_trait_in_assoc_type)]
-
+mod component;
+mod graph;
+mod inverse;
+mod iter;
 mod left;
+mod lookup;
 #[cfg(feature = "mlua")]
-mod mlua;
+pub mod mlua;
+mod ops;
+#[cfg(feature = "btreemap")]
+mod range;
+mod read;
+mod relation;
 mod right;
 #[cfg(feature = "serde")]
-mod serde;
+pub mod serde;
+mod values;
 
+#[cfg(feature = "btreemap")]
+pub use std::collections::{
+    BTreeMap,
+    BTreeMap as Map,
+    BTreeSet,
+    BTreeSet as Set,
+    btree_map::{Entry, Keys},
+};
+#[cfg(feature = "hashmap")]
+pub use std::collections::{
+    HashMap,
+    HashMap as Map,
+    HashSet,
+    HashSet as Set,
+    hash_map::{Entry, Keys},
+};
+#[cfg(feature = "btreemap")]
+use std::collections::{
+    btree_map::{IntoIter as MapIntoIter, Iter as MapIter},
+    btree_set::{IntoIter as SetIntoIter, Iter as SetIter},
+};
 #[cfg(feature = "hashmap")]
-pub use std::collections::{HashMap, HashSet, hash_map::Entry};
-use std::{borrow::Borrow, hash::Hash};
+use std::collections::{
+    hash_map::{IntoIter as MapIntoIter, Iter as MapIter},
+    hash_set::{IntoIter as SetIntoIter, Iter as SetIter},
+};
+#[cfg(any(feature = "hashmap", feature = "hashbrown"))]
+use std::hash::{BuildHasher, Hash};
 
 #[cfg(feature = "hashbrown")]
-pub use hashbrown::{HashMap, HashSet, hash_map::Entry};
+pub use hashbrown::{
+    HashMap,
+    HashMap as Map,
+    HashSet,
+    HashSet as Set,
+    hash_map::{Entry, Keys},
+};
+#[cfg(feature = "hashbrown")]
+use hashbrown::{
+    hash_map::{IntoIter as MapIntoIter, Iter as MapIter},
+    hash_set::{IntoIter as SetIntoIter, Iter as SetIter},
+};
+pub use {
+    component::{Component, Components},
+    graph::Reachable,
+    inverse::InverseView,
+    iter::{IntoIter, IntoPairs, Iter, IterEdges, IterRef, IterSorted},
+    left::{IterLeft, LeftEntry, LeftKeys, OccupiedLeftEntry, VacantLeftEntry},
+    ops::{Difference, Intersection, SymmetricDifference, Union},
+    read::BiMultiMapRead,
+    relation::Join,
+    right::{IterRight, OccupiedRightEntry, RightEntry, RightKeys, VacantRightEntry},
+    values::BiMultiMapWithValues,
+};
+use {
+    lookup::lookup,
+    std::{
+        borrow::Borrow,
+        fmt::{self, Debug, Formatter},
+    },
+};
 
 /// The reference counting type returned by the map
 #[cfg(feature = "thread-safe")]
@@ -22,75 +84,407 @@ pub type Rc<T> = std::sync::Arc<T>;
 #[cfg(not(feature = "thread-safe"))]
 pub type Rc<T> = std::rc::Rc<T>;
 
-#[derive(Debug, Default, PartialEq, Eq, Clone)]
-pub struct BiMultiMap<L: Hash + Eq, R: Hash + Eq> {
-    left_map_rc:  HashMap<Rc<L>, HashSet<Rc<R>>>,
-    right_map_rc: HashMap<Rc<R>, HashSet<Rc<L>>>,
+/// The bounds required on both sides of a [BiMultiMap]
+///
+/// It depends on the backend: `Hash + Eq` for `hashmap` and `hashbrown`, `Ord` for `btreemap`.
+#[cfg(any(feature = "hashmap", feature = "hashbrown"))]
+pub trait Key: Hash + Eq {}
+#[cfg(any(feature = "hashmap", feature = "hashbrown"))]
+impl<T: Hash + Eq + ?Sized> Key for T {}
+
+/// The bounds required on both sides of a [BiMultiMap]
+///
+/// It depends on the backend: `Hash + Eq` for `hashmap` and `hashbrown`, `Ord` for `btreemap`.
+#[cfg(feature = "btreemap")]
+pub trait Key: Ord {}
+#[cfg(feature = "btreemap")]
+impl<T: Ord + ?Sized> Key for T {}
+
+/// The hasher used when none is given to a [BiMultiMap]
+#[cfg(feature = "hashmap")]
+pub type DefaultHashBuilder = std::hash::RandomState;
+/// The hasher used when none is given to a [BiMultiMap]
+#[cfg(feature = "hashbrown")]
+pub type DefaultHashBuilder = hashbrown::DefaultHashBuilder;
+/// The hasher used when none is given to a [BiMultiMap]
+///
+/// `btreemap` doesn't hash anything, so this is only a placeholder.
+#[cfg(feature = "btreemap")]
+pub type DefaultHashBuilder = ();
+
+/// The bounds required on the hasher of a [BiMultiMap]
+///
+/// Each inner set is built with `S::default()`, hence the `Default` bound.
+#[cfg(any(feature = "hashmap", feature = "hashbrown"))]
+pub trait MapHasher: BuildHasher + Default + Clone {}
+#[cfg(any(feature = "hashmap", feature = "hashbrown"))]
+impl<S: BuildHasher + Default + Clone> MapHasher for S {}
+
+/// The bounds required on the hasher of a [BiMultiMap]
+///
+/// `btreemap` ignores the hasher, so any `Default + Clone` type works.
+#[cfg(feature = "btreemap")]
+pub trait MapHasher: Default + Clone {}
+#[cfg(feature = "btreemap")]
+impl<S: Default + Clone> MapHasher for S {}
+
+/// The map used by each side of a [BiMultiMap] hashed with `S`
+#[cfg(any(feature = "hashmap", feature = "hashbrown"))]
+pub type InnerMap<K, V, S = DefaultHashBuilder> = Map<K, V, S>;
+/// The set used by each side of a [BiMultiMap] hashed with `S`
+#[cfg(any(feature = "hashmap", feature = "hashbrown"))]
+pub type InnerSet<T, S = DefaultHashBuilder> = Set<T, S>;
+
+/// The map used by each side of a [BiMultiMap], `S` being ignored
+#[cfg(feature = "btreemap")]
+pub type InnerMap<K, V, S = DefaultHashBuilder> = Map<K, <S as Ignored<V>>::Type>;
+/// The set used by each side of a [BiMultiMap], `S` being ignored
+#[cfg(feature = "btreemap")]
+pub type InnerSet<T, S = DefaultHashBuilder> = Set<<S as Ignored<T>>::Type>;
+
+/// Resolves to `T` whatever `Self` is
+///
+/// Type aliases can't have unused parameters, this is what lets [InnerMap] and [InnerSet] take
+/// the hasher anyway.
+#[cfg(feature = "btreemap")]
+#[doc(hidden)]
+pub trait Ignored<T> {
+    type Type;
+}
+#[cfg(feature = "btreemap")]
+impl<S, T> Ignored<T> for S {
+    type Type = T;
+}
+
+/// The values added and removed by [BiMultiMap::set_left] or [BiMultiMap::set_right]
+pub struct SetDiff<T: Key, S: MapHasher = DefaultHashBuilder> {
+    pub added:   InnerSet<Rc<T>, S>,
+    pub removed: InnerSet<Rc<T>, S>,
+}
+
+impl<T: Key + Debug, S: MapHasher> Debug for SetDiff<T, S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SetDiff")
+            .field("added", &self.added)
+            .field("removed", &self.removed)
+            .finish()
+    }
+}
+
+impl<T: Key, S: MapHasher> PartialEq for SetDiff<T, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.added == other.added && self.removed == other.removed
+    }
+}
+
+impl<T: Key, S: MapHasher> Eq for SetDiff<T, S> {}
+
+impl<T: Key, S: MapHasher> Clone for SetDiff<T, S> {
+    fn clone(&self) -> Self {
+        SetDiff {
+            added:   self.added.clone(),
+            removed: self.removed.clone(),
+        }
+    }
+}
+
+/// A map whose keys are [Rc]s, in which [intern] looks for an existing allocation
+pub(crate) trait Interner<T> {
+    fn interned(&self, value: &T) -> Option<&Rc<T>>;
+}
+
+#[cfg(any(feature = "hashmap", feature = "hashbrown"))]
+impl<T: Key, V, S: BuildHasher> Interner<T> for Map<Rc<T>, V, S> {
+    fn interned(&self, value: &T) -> Option<&Rc<T>> {
+        self.get_key_value(value).map(|(value_rc, _)| value_rc)
+    }
+}
+
+#[cfg(feature = "btreemap")]
+impl<T: Key, V> Interner<T> for Map<Rc<T>, V> {
+    fn interned(&self, value: &T) -> Option<&Rc<T>> {
+        self.get_key_value(value).map(|(value_rc, _)| value_rc)
+    }
+}
+
+/// Returns the [Rc] already used as a key of `map` for `value`, or allocates a new one.
+///
+/// This keeps a single allocation per distinct value, shared by both sides of the [BiMultiMap].
+pub(crate) fn intern<T: Key>(map: &impl Interner<T>, value: T) -> Rc<T> {
+    map.interned(&value)
+        .cloned()
+        .unwrap_or_else(|| Rc::new(value))
+}
+
+/// Same as [intern], but for a value that is already in an [Rc]
+pub(crate) fn intern_rc<T: Key>(map: &impl Interner<T>, value: Rc<T>) -> Rc<T> {
+    map.interned(&value).cloned().unwrap_or(value)
+}
+
+/// A many-to-many map, hashing its keys with `S` when the backend hashes them
+///
+/// # Shared values
+///
+/// Each distinct value is stored once, in an [Rc] shared by both sides of the map. The methods
+/// building a map out of existing ones, like [BiMultiMap::union_with] or [BiMultiMap::compose],
+/// share these [Rc]s with the new mappings rather than cloning the values, so they don't need
+/// the values to implement [Clone].
+pub struct BiMultiMap<L: Key, R: Key, S: MapHasher = DefaultHashBuilder> {
+    left_map_rc:  InnerMap<Rc<L>, InnerSet<Rc<R>, S>, S>,
+    right_map_rc: InnerMap<Rc<R>, InnerSet<Rc<L>, S>, S>,
     len:          usize,
 }
 
-impl<L, R> IntoIterator for BiMultiMap<L, R>
-where
-    L: Hash + Eq + Clone,
-    R: Hash + Eq + Clone,
-{
+// Not derived, as `derive` would require `S` to implement these traits too
+
+impl<L: Key + Debug, R: Key + Debug, S: MapHasher> Debug for BiMultiMap<L, R, S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BiMultiMap")
+            .field("left_map_rc", &self.left_map_rc)
+            .field("right_map_rc", &self.right_map_rc)
+            .field("len", &self.len)
+            .finish()
+    }
+}
+
+impl<L: Key, R: Key, S: MapHasher> Default for BiMultiMap<L, R, S> {
+    fn default() -> Self {
+        BiMultiMap {
+            left_map_rc:  InnerMap::<_, _, S>::default(),
+            right_map_rc: InnerMap::<_, _, S>::default(),
+            len:          0usize,
+        }
+    }
+}
+
+impl<L: Key, R: Key, S: MapHasher> PartialEq for BiMultiMap<L, R, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len &&
+            self.left_map_rc == other.left_map_rc &&
+            self.right_map_rc == other.right_map_rc
+    }
+}
+
+impl<L: Key, R: Key, S: MapHasher> Eq for BiMultiMap<L, R, S> {}
+
+impl<L: Key, R: Key, S: MapHasher> Clone for BiMultiMap<L, R, S> {
+    fn clone(&self) -> Self {
+        BiMultiMap {
+            left_map_rc:  self.left_map_rc.clone(),
+            right_map_rc: self.right_map_rc.clone(),
+            len:          self.len,
+        }
+    }
+}
+
+impl<L: Key, R: Key, S: MapHasher> IntoIterator for BiMultiMap<L, R, S> {
+    type IntoIter = IntoIter<L, R, S>;
     type Item = (Rc<L>, Rc<R>);
 
-    type IntoIter = impl Iterator<Item = (Rc<L>, Rc<R>)>;
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter::new(self)
+    }
+}
+
+impl<'a, L: Key, R: Key, S: MapHasher> IntoIterator for &'a BiMultiMap<L, R, S> {
+    type IntoIter = Iter<'a, L, R, S>;
+    type Item = (&'a Rc<L>, &'a Rc<R>);
 
     fn into_iter(self) -> Self::IntoIter {
-        gen move {
-            for (left, rights) in self.left_map_rc {
-                for right in rights {
-                    yield (left.clone(), right);
-                }
-            }
-        }
+        self.iter()
     }
 }
 
 impl<L, R> FromIterator<(L, R)> for BiMultiMap<L, R>
 where
-    L: Hash + Eq,
-    R: Hash + Eq,
+    L: Key,
+    R: Key,
 {
     fn from_iter<T: IntoIterator<Item = (L, R)>>(iter: T) -> Self {
         let mut map = BiMultiMap::new();
-        iter.into_iter()
-            .for_each(|(left, right)| map.insert(left, right));
+        map.extend(iter);
         map
     }
 }
 
-impl<L: Hash + Eq, R: Hash + Eq> BiMultiMap<L, R> {
+/// Only for the default hasher, so that `BiMultiMap::from([...])` needs no type annotation
+impl<L: Key, R: Key, const N: usize> From<[(L, R); N]> for BiMultiMap<L, R> {
+    fn from(pairs: [(L, R); N]) -> Self {
+        BiMultiMap::from_iter(pairs)
+    }
+}
+
+impl<L, R, S> Extend<(L, R)> for BiMultiMap<L, R, S>
+where
+    L: Key,
+    R: Key,
+    S: MapHasher,
+{
+    fn extend<T: IntoIterator<Item = (L, R)>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|(left, right)| {
+            self.insert(left, right);
+        });
+    }
+}
+
+impl<'a, L, R, S> Extend<(&'a L, &'a R)> for BiMultiMap<L, R, S>
+where
+    L: Key + Copy,
+    R: Key + Copy,
+    S: MapHasher,
+{
+    fn extend<T: IntoIterator<Item = (&'a L, &'a R)>>(&mut self, iter: T) {
+        self.extend(iter.into_iter().map(|(&left, &right)| (left, right)));
+    }
+}
+
+impl<L: Key, R: Key> BiMultiMap<L, R> {
     pub fn new() -> Self {
         BiMultiMap {
-            left_map_rc:  HashMap::new(),
-            right_map_rc: HashMap::new(),
+            left_map_rc:  Map::new(),
+            right_map_rc: Map::new(),
             len:          0usize,
         }
     }
 
-    pub fn iter(&self) -> impl Iterator<Item = (&Rc<L>, &Rc<R>)> {
-        gen {
-            for (left, rights) in self.left_map_rc.iter() {
-                for right in rights {
-                    yield (left, right);
-                }
-            }
+    /// Creates an empty [BiMultiMap] with room for `left` left keys and `right` right keys
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let map = bimultimap::BiMultiMap::<char, u8>::with_capacity(10, 20);
+    ///
+    /// assert!(map.left_capacity() >= 10);
+    /// assert!(map.right_capacity() >= 20);
+    /// ```
+    #[cfg(any(feature = "hashmap", feature = "hashbrown"))]
+    pub fn with_capacity(left: usize, right: usize) -> Self {
+        Self::with_capacity_and_hasher(left, right, DefaultHashBuilder::default())
+    }
+}
+
+impl<L: Key, R: Key, S: MapHasher> BiMultiMap<L, R, S> {
+    /// Creates an empty [BiMultiMap] hashing its keys with `hasher`
+    ///
+    /// Both sides use a clone of `hasher`, and each set of partners uses `S::default()`.
+    #[cfg(any(feature = "hashmap", feature = "hashbrown"))]
+    pub fn with_hasher(hasher: S) -> Self {
+        Self::with_capacity_and_hasher(0, 0, hasher)
+    }
+
+    /// Same as [BiMultiMap::with_capacity], hashing the keys with `hasher`
+    #[cfg(any(feature = "hashmap", feature = "hashbrown"))]
+    pub fn with_capacity_and_hasher(left: usize, right: usize, hasher: S) -> Self {
+        BiMultiMap {
+            left_map_rc:  InnerMap::with_capacity_and_hasher(left, hasher.clone()),
+            right_map_rc: InnerMap::with_capacity_and_hasher(right, hasher),
+            len:          0usize,
         }
     }
 
-    pub fn iter_ref(&self) -> impl Iterator<Item = (&L, &R)> {
-        gen {
-            for (left, rights) in self.left_map_rc.iter() {
-                for right in rights {
-                    yield (left.borrow(), right.borrow());
-                }
-            }
+    /// An empty [BiMultiMap], possibly of other types, using the same hasher as `self`
+    #[cfg(any(feature = "hashmap", feature = "hashbrown"))]
+    pub(crate) fn new_like<L2: Key, R2: Key>(&self) -> BiMultiMap<L2, R2, S> {
+        BiMultiMap::with_hasher(self.left_map_rc.hasher().clone())
+    }
+
+    /// An empty [BiMultiMap], possibly of other types, using the same hasher as `self`
+    #[cfg(feature = "btreemap")]
+    pub(crate) fn new_like<L2: Key, R2: Key>(&self) -> BiMultiMap<L2, R2, S> {
+        BiMultiMap::default()
+    }
+
+    /// Shrinks both sides, and every set of partners, as much as possible
+    #[cfg(any(feature = "hashmap", feature = "hashbrown"))]
+    pub fn shrink_to_fit(&mut self) {
+        self.left_map_rc.shrink_to_fit();
+        self.left_map_rc
+            .values_mut()
+            .for_each(InnerSet::shrink_to_fit);
+        self.right_map_rc.shrink_to_fit();
+        self.right_map_rc
+            .values_mut()
+            .for_each(InnerSet::shrink_to_fit);
+    }
+
+    /// Swaps the sides of the map, without moving any value
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let map = bimultimap::BiMultiMap::from_iter([('a', 0), ('b', 0)]);
+    /// let inverse = map.into_inverse();
+    ///
+    /// assert_eq!(inverse.left_degree(&0), 2);
+    /// assert!(inverse.contains(&0, &'a'));
+    /// ```
+    pub fn into_inverse(self) -> BiMultiMap<R, L, S> {
+        BiMultiMap {
+            left_map_rc:  self.right_map_rc,
+            right_map_rc: self.left_map_rc,
+            len:          self.len,
         }
     }
 
+    /// Borrows the map with its sides swapped
+    pub fn as_inverse(&self) -> InverseView<'_, R, L, S> {
+        InverseView::new(self)
+    }
+
+    /// Iterates over all the mappings, as the [Rc]s stored in the map
+    pub fn iter(&self) -> Iter<'_, L, R, S> {
+        Iter::new(self)
+    }
+
+    /// Iterates over references to all the mappings
+    pub fn iter_ref(&self) -> IterRef<'_, L, R, S> {
+        IterRef::new(self)
+    }
+
+    /// Iterates over references to all the mappings, sorted by left value then by right value
+    ///
+    /// Unlike [BiMultiMap::iter_ref], the order doesn't depend on the insertion order or on the
+    /// hasher, so equal maps always yield the same sequence. It collects and sorts the mappings
+    /// first, in `O(n log n)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let map = bimultimap::BiMultiMap::from_iter([('b', 0), ('a', 1), ('a', 0)]);
+    ///
+    /// assert_eq!(
+    ///     map.iter_sorted().collect::<Vec<_>>(),
+    ///     vec![(&'a', &0), (&'a', &1), (&'b', &0)]
+    /// );
+    /// ```
+    pub fn iter_sorted(&self) -> IterSorted<'_, L, R>
+    where
+        L: Ord,
+        R: Ord,
+    {
+        IterSorted::new(self.iter_ref())
+    }
+
+    /// Consumes the map into all its mappings, as owned values
+    ///
+    /// A value is only cloned when it's part of several mappings, or when it's shared with a
+    /// clone of the map.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let map = bimultimap::BiMultiMap::from_iter([(String::from("a"), 0)]);
+    ///
+    /// assert_eq!(map.into_pairs().collect::<Vec<_>>(), vec![(String::from("a"), 0)]);
+    /// ```
+    pub fn into_pairs(self) -> IntoPairs<L, R, S>
+    where
+        L: Clone,
+        R: Clone,
+    {
+        IntoPairs::new(self)
+    }
+
     /// Represents the number of how many unique mappings there are
     ///
     /// # Example
@@ -108,11 +502,107 @@ impl<L: Hash + Eq, R: Hash + Eq> BiMultiMap<L, R> {
         self.len
     }
 
+    /// Whether `left` is mapped to `right`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let map = bimultimap::BiMultiMap::from_iter([('a', 0), ('b', 1)]);
+    ///
+    /// assert!(map.contains(&'a', &0));
+    /// assert!(!map.contains(&'a', &1));
+    /// ```
+    pub fn contains<QL, QR>(&self, left: &QL, right: &QR) -> bool
+    where
+        L: Borrow<QL>,
+        R: Borrow<QR>,
+        QL: Key + ?Sized,
+        QR: Key + ?Sized,
+    {
+        self.get_left(left)
+            .is_some_and(|right_set| right_set.contains(lookup(&right)))
+    }
+
+    /// Whether there is no mapping in the [BiMultiMap]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Checks that the left and right maps are exact mirrors of each other, that no key is mapped
+    /// to an empty set and that [BiMultiMap::len] is the number of mappings.
+    ///
+    /// This is a `O(n)` operation, meant to be used in tests or in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of these invariants doesn't hold.
+    pub fn check_invariants(&self) {
+        let mut left_len = 0usize;
+
+        for (left, rights) in self.left_map_rc.iter() {
+            assert!(!rights.is_empty(), "a left key is mapped to an empty set");
+
+            for right in rights {
+                assert!(
+                    self.right_map_rc
+                        .get(right)
+                        .is_some_and(|lefts| lefts.contains(left)),
+                    "a mapping of the left map is missing from the right map"
+                );
+            }
+
+            left_len += rights.len();
+        }
+
+        let mut right_len = 0usize;
+
+        for (right, lefts) in self.right_map_rc.iter() {
+            assert!(!lefts.is_empty(), "a right key is mapped to an empty set");
+
+            for left in lefts {
+                assert!(
+                    self.left_map_rc
+                        .get(left)
+                        .is_some_and(|rights| rights.contains(right)),
+                    "a mapping of the right map is missing from the left map"
+                );
+            }
+
+            right_len += lefts.len();
+        }
+
+        assert_eq!(
+            left_len, right_len,
+            "left and right maps have a different length"
+        );
+        assert_eq!(
+            self.len, left_len,
+            "`len` doesn't match the number of mappings"
+        );
+    }
+
     /// Inserts a (L, R) in the [BiMultiMap]
-    pub fn insert(&mut self, left: L, right: R) {
-        let left_rc = Rc::new(left);
-        let right_rc = Rc::new(right);
+    ///
+    /// Returns whether the mapping was added, like
+    /// [HashSet::insert](std::collections::HashSet::insert).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut map = bimultimap::BiMultiMap::new();
+    ///
+    /// assert!(map.insert('a', 0));
+    /// assert!(!map.insert('a', 0));
+    /// ```
+    pub fn insert(&mut self, left: L, right: R) -> bool {
+        let left_rc = intern(&self.left_map_rc, left);
+        let right_rc = intern(&self.right_map_rc, right);
 
+        self.insert_rc(left_rc, right_rc)
+    }
+
+    /// Same as [BiMultiMap::insert], for values already interned in the map
+    pub(crate) fn insert_rc(&mut self, left_rc: Rc<L>, right_rc: Rc<R>) -> bool {
         let mut was_added = false;
 
         self.right_map_rc
@@ -122,7 +612,7 @@ impl<L: Hash + Eq, R: Hash + Eq> BiMultiMap<L, R> {
             })
             .or_insert_with(|| {
                 was_added = true;
-                HashSet::from_iter([left_rc.clone()])
+                InnerSet::<_, S>::from_iter([left_rc.clone()])
             });
 
         self.left_map_rc
@@ -132,30 +622,114 @@ impl<L: Hash + Eq, R: Hash + Eq> BiMultiMap<L, R> {
             })
             .or_insert_with(|| {
                 was_added = true;
-                HashSet::from_iter([right_rc.clone()])
+                InnerSet::<_, S>::from_iter([right_rc.clone()])
             });
 
         if was_added {
             self.len += 1;
         }
+
+        was_added
     }
 
-    /// Remove an existing mapping between Left and Right.
+    /// Same as [BiMultiMap::insert], for values that may already be in an [Rc], like the ones of
+    /// another map
+    pub(crate) fn insert_shared(&mut self, left: Rc<L>, right: Rc<R>) -> bool {
+        let left_rc = intern_rc(&self.left_map_rc, left);
+        let right_rc = intern_rc(&self.right_map_rc, right);
+
+        self.insert_rc(left_rc, right_rc)
+    }
+
+    /// Removes all the mappings
+    pub fn clear(&mut self) {
+        self.left_map_rc.clear();
+        self.right_map_rc.clear();
+        self.len = 0;
+    }
+
+    /// Removes all the mappings, returning them in an iterator
+    pub fn drain(&mut self) -> IntoIter<L, R, S> {
+        let empty = self.new_like();
+
+        IntoIter::new(std::mem::replace(self, empty))
+    }
+
+    /// Only keeps the mappings for which `f` returns `true`
     ///
-    /// Returns whether the mapping was removed.
-    pub fn remove<LeftRef: Borrow<L>, RightRef: Borrow<R>>(
+    /// Each side is only traversed once, whatever the number of removed mappings.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut map = bimultimap::BiMultiMap::from_iter([(0, 0), (0, 1), (1, 1)]);
+    ///
+    /// map.retain(|left, right| left == right);
+    ///
+    /// assert_eq!(map.len(), 2);
+    /// assert_eq!(map.get_left_vec(&0), Some(vec![&0]));
+    /// ```
+    pub fn retain<F: FnMut(&L, &R) -> bool>(&mut self, mut f: F) {
+        self.extract_if(|left, right| !f(left, right));
+    }
+
+    /// Removes the mappings for which `f` returns `true`, returning them in an iterator
+    ///
+    /// Unlike [Vec::extract_if], the mappings are removed eagerly: they are removed even if the
+    /// returned iterator isn't consumed.
+    pub fn extract_if<F: FnMut(&L, &R) -> bool>(
         &mut self,
-        left: LeftRef,
-        right: RightRef,
-    ) -> bool {
-        let left = left.borrow();
-        let right = right.borrow();
+        mut f: F,
+    ) -> std::vec::IntoIter<(Rc<L>, Rc<R>)> {
+        let mut extracted = Vec::new();
+
+        self.left_map_rc.retain(|left, right_set| {
+            right_set.retain(|right| {
+                let should_extract = f(left, right);
+
+                if should_extract {
+                    extracted.push((left.clone(), right.clone()));
+                }
+
+                !should_extract
+            });
+
+            !right_set.is_empty()
+        });
+
+        self.len -= extracted.len();
+
+        for (left, right) in extracted.iter() {
+            let should_remove_right = self.right_map_rc.get_mut(right).map(|left_set| {
+                left_set.remove(left);
+                left_set.is_empty()
+            });
 
+            if should_remove_right == Some(true) {
+                self.right_map_rc.remove(right);
+            }
+        }
+
+        extracted.into_iter()
+    }
+
+    /// Remove an existing mapping between Left and Right.
+    ///
+    /// Returns whether the mapping was removed.
+    ///
+    /// Both keys may be any borrowed form of their type.
+    pub fn remove<QL, QR>(&mut self, left: &QL, right: &QR) -> bool
+    where
+        L: Borrow<QL>,
+        R: Borrow<QR>,
+        QL: Key + ?Sized,
+        QR: Key + ?Sized,
+    {
         let can_be_removed = self
             .get_left(left)
-            .is_some_and(|right_set| right_set.contains(right)) &&
+            .is_some_and(|right_set| right_set.contains(lookup(&right))) &&
             self.get_right(right)
-                .is_some_and(|left_set| left_set.contains(left));
+                .is_some_and(|left_set| left_set.contains(lookup(&left)));
 
         if can_be_removed {
             self.len -= 1;
@@ -163,21 +737,21 @@ impl<L: Hash + Eq, R: Hash + Eq> BiMultiMap<L, R> {
 
         if can_be_removed {
             let should_remove_left = self.get_mut_left(left).map(|right_set| {
-                right_set.remove(right);
+                right_set.remove(lookup(&right));
                 right_set.is_empty()
             });
 
             if should_remove_left == Some(true) {
-                self.left_map_rc.remove(left);
+                self.left_map_rc.remove(lookup(&left));
             }
 
             let should_remove_right = self.get_mut_right(right).map(|left_set| {
-                left_set.remove(left);
+                left_set.remove(lookup(&left));
                 left_set.is_empty()
             });
 
             if should_remove_right == Some(true) {
-                self.right_map_rc.remove(right);
+                self.right_map_rc.remove(lookup(&right));
             }
 
             true
@@ -0,0 +1,67 @@
+//! Lookups by any type a key borrows as.
+//!
+//! The maps are keyed by `Rc<T>`, which only implements `Borrow<T>`, so a `BiMultiMap<String, _>`
+//! couldn't be queried with a `&str`. Instead, both the stored `Rc<T>` and the queried `&Q`
+//! borrow as a `dyn Lookup<Q>`, which hashes and compares like `Q`.
+
+use {
+    crate::Rc,
+    std::{
+        borrow::Borrow,
+        cmp::Ordering,
+        hash::{Hash, Hasher},
+    },
+};
+
+pub trait Lookup<Q: ?Sized> {
+    fn key(&self) -> &Q;
+}
+
+impl<Q: ?Sized> Lookup<Q> for &Q {
+    fn key(&self) -> &Q {
+        self
+    }
+}
+
+impl<T: Borrow<Q>, Q: ?Sized> Lookup<Q> for Rc<T> {
+    fn key(&self) -> &Q {
+        (**self).borrow()
+    }
+}
+
+impl<'a, T: Borrow<Q> + 'a, Q: ?Sized + 'a> Borrow<dyn Lookup<Q> + 'a> for Rc<T> {
+    fn borrow(&self) -> &(dyn Lookup<Q> + 'a) {
+        self
+    }
+}
+
+/// Turns a queried key into something the inner maps and sets can be indexed with
+pub(crate) fn lookup<'a, 'b, Q: ?Sized>(key: &'a &'b Q) -> &'a (dyn Lookup<Q> + 'b) {
+    key
+}
+
+impl<Q: ?Sized + Hash> Hash for dyn Lookup<Q> + '_ {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key().hash(state)
+    }
+}
+
+impl<Q: ?Sized + PartialEq> PartialEq for dyn Lookup<Q> + '_ {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl<Q: ?Sized + Eq> Eq for dyn Lookup<Q> + '_ {}
+
+impl<Q: ?Sized + PartialOrd> PartialOrd for dyn Lookup<Q> + '_ {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.key().partial_cmp(other.key())
+    }
+}
+
+impl<Q: ?Sized + Ord> Ord for dyn Lookup<Q> + '_ {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key().cmp(other.key())
+    }
+}
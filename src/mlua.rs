@@ -1,14 +1,52 @@
+//! mlua support
+//!
+//! [BiMultiMap] converts to and from a flat Lua table, with one entry per mapping. Since a Lua
+//! table can't repeat a key, a left value keeps only one of its right values in Lua. These
+//! wrappers convert every mapping instead:
+//!
+//! - [GroupedByLeft]: `{ admin = {"alice", "bob"}, user = {"bob"} }`, a table from each left
+//!   value to the sequence of its right values
+//! - [GroupedByRight]: `{ alice = {"admin"}, bob = {"admin", "user"} }`, the same from each right
+//!   value
+//!
+//! Like with the grouped serde representations, converting them from Lua fails if a value is
+//! repeated, or mapped to an empty sequence or to the same partner twice. It also fails if a value
+//! is mapped to a table with other keys than `1..=n`.
+//!
+//! ```
+//! use bimultimap::{BiMultiMap, mlua::GroupedByLeft};
+//!
+//! let lua = mlua::Lua::new();
+//! let members = BiMultiMap::from_iter([("admin", "alice"), ("admin", "bob")]);
+//!
+//! lua.globals().set("members", GroupedByLeft(members)).unwrap();
+//!
+//! assert_eq!(lua.load("#members.admin").eval::<usize>().unwrap(), 2);
+//! ```
+
 use {
-    crate::BiMultiMap,
-    mlua::{Error, FromLua, IntoLua, Lua, Value},
-    std::{hash::Hash, ops::Deref},
+    crate::{BiMultiMap, DefaultHashBuilder, InnerMap, InnerSet, Key, MapHasher, Rc},
+    mlua::{Error, FromLua, IntoLua, Lua, Table, Value},
+    std::ops::Deref,
 };
 
-impl<K: Eq + Hash + FromLua, V: FromLua + Eq + Hash> FromLua for BiMultiMap<K, V> {
+impl<L, R, S> FromLua for BiMultiMap<L, R, S>
+where
+    L: Key + FromLua,
+    R: Key + FromLua,
+    S: MapHasher,
+{
     #[inline]
     fn from_lua(value: Value, _: &Lua) -> mlua::Result<Self> {
         if let Value::Table(table) = value {
-            table.pairs().collect()
+            let mut map = BiMultiMap::default();
+
+            for pair in table.pairs() {
+                let (left, right) = pair?;
+                map.insert(left, right);
+            }
+
+            Ok(map)
         } else {
             Err(Error::FromLuaConversionError {
                 from:    value.type_name(),
@@ -19,10 +57,11 @@ impl<K: Eq + Hash + FromLua, V: FromLua + Eq + Hash> FromLua for BiMultiMap<K, V
     }
 }
 
-impl<K, V> IntoLua for BiMultiMap<K, V>
+impl<L, R, S> IntoLua for BiMultiMap<L, R, S>
 where
-    K: Eq + Hash + IntoLua + Clone,
-    V: IntoLua + Hash + Eq + Clone,
+    L: Key + IntoLua + Clone,
+    R: Key + IntoLua + Clone,
+    S: MapHasher,
 {
     #[inline]
     fn into_lua(self, lua: &Lua) -> mlua::Result<Value> {
@@ -34,3 +73,169 @@ where
         ))
     }
 }
+
+/// A [BiMultiMap] converted to and from a Lua table from each left value to the sequence of its
+/// right values
+pub struct GroupedByLeft<L: Key, R: Key, S: MapHasher = DefaultHashBuilder>(
+    pub BiMultiMap<L, R, S>,
+);
+
+/// A [BiMultiMap] converted to and from a Lua table from each right value to the sequence of its
+/// left values
+pub struct GroupedByRight<L: Key, R: Key, S: MapHasher = DefaultHashBuilder>(
+    pub BiMultiMap<L, R, S>,
+);
+
+/// Converts one side of a map to a table from each key to the sequence of its partners
+fn side_into_lua<K, V, S>(
+    side: &InnerMap<Rc<K>, InnerSet<Rc<V>, S>, S>,
+    lua: &Lua,
+) -> mlua::Result<Value>
+where
+    K: Key + IntoLua + Clone,
+    V: Key + IntoLua + Clone,
+    S: MapHasher,
+{
+    let table = lua.create_table_with_capacity(0, side.len())?;
+
+    for (key, values) in side {
+        table.raw_set(
+            key.deref().clone(),
+            lua.create_sequence_from(values.iter().map(|value| value.deref().clone()))?,
+        )?;
+    }
+
+    Ok(Value::Table(table))
+}
+
+/// Converts a table from each key to the sequence of its partners, failing if a key is repeated, or
+/// mapped to a table that isn't a sequence, to an empty sequence or to the same partner twice
+///
+/// `keys` and `values` name the sides of the keys and of their partners in the errors.
+fn groups_from_lua<K, V, S>(
+    value: Value,
+    to: &str,
+    keys: &str,
+    values: &str,
+) -> mlua::Result<BiMultiMap<K, V, S>>
+where
+    K: Key + FromLua,
+    V: Key + FromLua,
+    S: MapHasher,
+{
+    let error = |from, message: String| {
+        Error::FromLuaConversionError {
+            from,
+            to: String::from(to),
+            message: Some(message),
+        }
+    };
+
+    let Value::Table(table) = value else {
+        return Err(error(
+            value.type_name(),
+            "expected a table of sequences".to_string(),
+        ));
+    };
+
+    let mut map = BiMultiMap::default();
+
+    table.for_each(|key: K, group: Table| {
+        if map.contains_left(&key) {
+            return Err(error("table", format!("a {keys} value appears twice")));
+        }
+
+        let not_sequence = || {
+            error(
+                "table",
+                format!(
+                    "a {keys} value is mapped to a table that isn't a sequence of {values} values"
+                ),
+            )
+        };
+
+        let len = group.raw_len();
+        let mut partners = Vec::with_capacity(len);
+
+        // A sequence only has the keys `1..=len`, so any other key or any gap is an error
+        for pair in group.pairs::<Value, V>() {
+            match pair? {
+                (Value::Integer(index), partner) if (1..=len as i64).contains(&index) => {
+                    partners.push(partner);
+                },
+                _ => return Err(not_sequence()),
+            }
+        }
+
+        if partners.len() != len {
+            return Err(not_sequence());
+        }
+
+        if len == 0 {
+            return Err(error(
+                "table",
+                format!("a {keys} value is mapped to an empty sequence of {values} values"),
+            ));
+        }
+
+        if map.extend_left(key, partners) != len {
+            return Err(error(
+                "table",
+                format!("a {keys} value is mapped to the same {values} value twice"),
+            ));
+        }
+
+        Ok(())
+    })?;
+
+    Ok(map)
+}
+
+impl<L, R, S> IntoLua for GroupedByLeft<L, R, S>
+where
+    L: Key + IntoLua + Clone,
+    R: Key + IntoLua + Clone,
+    S: MapHasher,
+{
+    #[inline]
+    fn into_lua(self, lua: &Lua) -> mlua::Result<Value> {
+        side_into_lua::<L, R, S>(&self.0.left_map_rc, lua)
+    }
+}
+
+impl<L, R, S> FromLua for GroupedByLeft<L, R, S>
+where
+    L: Key + FromLua,
+    R: Key + FromLua,
+    S: MapHasher,
+{
+    #[inline]
+    fn from_lua(value: Value, _: &Lua) -> mlua::Result<Self> {
+        groups_from_lua(value, "GroupedByLeft", "left", "right").map(GroupedByLeft)
+    }
+}
+
+impl<L, R, S> IntoLua for GroupedByRight<L, R, S>
+where
+    L: Key + IntoLua + Clone,
+    R: Key + IntoLua + Clone,
+    S: MapHasher,
+{
+    #[inline]
+    fn into_lua(self, lua: &Lua) -> mlua::Result<Value> {
+        side_into_lua::<R, L, S>(&self.0.right_map_rc, lua)
+    }
+}
+
+impl<L, R, S> FromLua for GroupedByRight<L, R, S>
+where
+    L: Key + FromLua,
+    R: Key + FromLua,
+    S: MapHasher,
+{
+    #[inline]
+    fn from_lua(value: Value, _: &Lua) -> mlua::Result<Self> {
+        groups_from_lua::<R, L, S>(value, "GroupedByRight", "right", "left")
+            .map(|map| GroupedByRight(map.into_inverse()))
+    }
+}
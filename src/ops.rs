@@ -0,0 +1,260 @@
+use {
+    crate::{BiMultiMap, DefaultHashBuilder, Iter, Key, MapHasher, Rc},
+    std::{
+        iter::{Chain, FusedIterator},
+        ops::{BitAnd, BitOr, BitXor, Sub},
+    },
+};
+
+/// Set algebra, where each (L, R) mapping is an element, like for [PartialEq]
+impl<L: Key, R: Key, S: MapHasher> BiMultiMap<L, R, S> {
+    /// Iterates over the mappings of `self` or `other`, without duplicates
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let before = bimultimap::BiMultiMap::from_iter([('a', 0), ('a', 1)]);
+    /// let after = bimultimap::BiMultiMap::from_iter([('a', 1), ('b', 1)]);
+    ///
+    /// assert_eq!(before.union(&after).count(), 3);
+    /// assert_eq!(before.intersection(&after).count(), 1);
+    /// assert_eq!(before.difference(&after).count(), 1);
+    /// assert_eq!(before.symmetric_difference(&after).count(), 2);
+    /// ```
+    pub fn union<'a>(&'a self, other: &'a Self) -> Union<'a, L, R, S> {
+        Union {
+            iter: self.iter().chain(other.difference(self)),
+        }
+    }
+
+    /// Iterates over the mappings of both `self` and `other`
+    pub fn intersection<'a>(&'a self, other: &'a Self) -> Intersection<'a, L, R, S> {
+        // Only the smallest map is traversed
+        let (smallest, largest) = if self.len() <= other.len() {
+            (self, other)
+        } else {
+            (other, self)
+        };
+
+        Intersection {
+            iter:  smallest.iter(),
+            other: largest,
+        }
+    }
+
+    /// Iterates over the mappings of `self` that aren't in `other`
+    pub fn difference<'a>(&'a self, other: &'a Self) -> Difference<'a, L, R, S> {
+        Difference {
+            iter: self.iter(),
+            other,
+        }
+    }
+
+    /// Iterates over the mappings of either `self` or `other`, but not both
+    pub fn symmetric_difference<'a>(&'a self, other: &'a Self) -> SymmetricDifference<'a, L, R, S> {
+        SymmetricDifference {
+            iter: self.difference(other).chain(other.difference(self)),
+        }
+    }
+
+    /// Adds the mappings of `other` to `self`
+    ///
+    /// The values are [shared](BiMultiMap#shared-values) with `other`.
+    pub fn union_with(&mut self, other: &Self) {
+        for (left, right) in other.iter() {
+            self.insert_shared(left.clone(), right.clone());
+        }
+    }
+
+    /// Only keeps the mappings of `self` that are also in `other`
+    pub fn intersection_with(&mut self, other: &Self) {
+        self.retain(|left, right| other.contains(left, right));
+    }
+
+    /// Removes the mappings of `other` from `self`
+    pub fn difference_with(&mut self, other: &Self) {
+        for (left, right) in other.iter() {
+            self.remove(&**left, &**right);
+        }
+    }
+
+    /// Only keeps the mappings that are in either `self` or `other`, but not both
+    ///
+    /// The values added are [shared](BiMultiMap#shared-values) with `other`.
+    pub fn symmetric_difference_with(&mut self, other: &Self) {
+        for (left, right) in other.iter() {
+            if !self.remove(&**left, &**right) {
+                self.insert_shared(left.clone(), right.clone());
+            }
+        }
+    }
+}
+
+/// An iterator over the mappings of two [BiMultiMap]s, obtained with [BiMultiMap::union]
+pub struct Union<'a, L: Key, R: Key, S: MapHasher = DefaultHashBuilder> {
+    iter: Chain<Iter<'a, L, R, S>, Difference<'a, L, R, S>>,
+}
+
+impl<'a, L: Key, R: Key, S: MapHasher> Iterator for Union<'a, L, R, S> {
+    type Item = (&'a Rc<L>, &'a Rc<R>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<L: Key, R: Key, S: MapHasher> FusedIterator for Union<'_, L, R, S> {}
+
+impl<L: Key, R: Key, S: MapHasher> Clone for Union<'_, L, R, S> {
+    fn clone(&self) -> Self {
+        Union {
+            iter: self.iter.clone(),
+        }
+    }
+}
+
+/// An iterator over the mappings shared by two [BiMultiMap]s, obtained with
+/// [BiMultiMap::intersection]
+pub struct Intersection<'a, L: Key, R: Key, S: MapHasher = DefaultHashBuilder> {
+    iter:  Iter<'a, L, R, S>,
+    other: &'a BiMultiMap<L, R, S>,
+}
+
+impl<'a, L: Key, R: Key, S: MapHasher> Iterator for Intersection<'a, L, R, S> {
+    type Item = (&'a Rc<L>, &'a Rc<R>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .find(|(left, right)| self.other.contains(&***left, &***right))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<L: Key, R: Key, S: MapHasher> FusedIterator for Intersection<'_, L, R, S> {}
+
+impl<L: Key, R: Key, S: MapHasher> Clone for Intersection<'_, L, R, S> {
+    fn clone(&self) -> Self {
+        Intersection {
+            iter:  self.iter.clone(),
+            other: self.other,
+        }
+    }
+}
+
+/// An iterator over the mappings of a [BiMultiMap] that aren't in another, obtained with
+/// [BiMultiMap::difference]
+pub struct Difference<'a, L: Key, R: Key, S: MapHasher = DefaultHashBuilder> {
+    iter:  Iter<'a, L, R, S>,
+    other: &'a BiMultiMap<L, R, S>,
+}
+
+impl<'a, L: Key, R: Key, S: MapHasher> Iterator for Difference<'a, L, R, S> {
+    type Item = (&'a Rc<L>, &'a Rc<R>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .find(|(left, right)| !self.other.contains(&***left, &***right))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<L: Key, R: Key, S: MapHasher> FusedIterator for Difference<'_, L, R, S> {}
+
+impl<L: Key, R: Key, S: MapHasher> Clone for Difference<'_, L, R, S> {
+    fn clone(&self) -> Self {
+        Difference {
+            iter:  self.iter.clone(),
+            other: self.other,
+        }
+    }
+}
+
+/// An iterator over the mappings of either of two [BiMultiMap]s but not both, obtained with
+/// [BiMultiMap::symmetric_difference]
+pub struct SymmetricDifference<'a, L: Key, R: Key, S: MapHasher = DefaultHashBuilder> {
+    iter: Chain<Difference<'a, L, R, S>, Difference<'a, L, R, S>>,
+}
+
+impl<'a, L: Key, R: Key, S: MapHasher> Iterator for SymmetricDifference<'a, L, R, S> {
+    type Item = (&'a Rc<L>, &'a Rc<R>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<L: Key, R: Key, S: MapHasher> FusedIterator for SymmetricDifference<'_, L, R, S> {}
+
+impl<L: Key, R: Key, S: MapHasher> Clone for SymmetricDifference<'_, L, R, S> {
+    fn clone(&self) -> Self {
+        SymmetricDifference {
+            iter: self.iter.clone(),
+        }
+    }
+}
+
+impl<L: Key, R: Key, S: MapHasher> BitOr<&BiMultiMap<L, R, S>> for &BiMultiMap<L, R, S> {
+    type Output = BiMultiMap<L, R, S>;
+
+    /// Returns the union of `self` and `rhs` as a new [BiMultiMap]
+    fn bitor(self, rhs: &BiMultiMap<L, R, S>) -> Self::Output {
+        let mut map = self.clone();
+        map.union_with(rhs);
+        map
+    }
+}
+
+impl<L: Key, R: Key, S: MapHasher> BitAnd<&BiMultiMap<L, R, S>> for &BiMultiMap<L, R, S> {
+    type Output = BiMultiMap<L, R, S>;
+
+    /// Returns the intersection of `self` and `rhs` as a new [BiMultiMap]
+    fn bitand(self, rhs: &BiMultiMap<L, R, S>) -> Self::Output {
+        let mut map = self.new_like();
+
+        for (left, right) in self.intersection(rhs) {
+            map.insert_shared(left.clone(), right.clone());
+        }
+
+        map
+    }
+}
+
+impl<L: Key, R: Key, S: MapHasher> Sub<&BiMultiMap<L, R, S>> for &BiMultiMap<L, R, S> {
+    type Output = BiMultiMap<L, R, S>;
+
+    /// Returns the difference of `self` and `rhs` as a new [BiMultiMap]
+    fn sub(self, rhs: &BiMultiMap<L, R, S>) -> Self::Output {
+        let mut map = self.new_like();
+
+        for (left, right) in self.difference(rhs) {
+            map.insert_shared(left.clone(), right.clone());
+        }
+
+        map
+    }
+}
+
+impl<L: Key, R: Key, S: MapHasher> BitXor<&BiMultiMap<L, R, S>> for &BiMultiMap<L, R, S> {
+    type Output = BiMultiMap<L, R, S>;
+
+    /// Returns the symmetric difference of `self` and `rhs` as a new [BiMultiMap]
+    fn bitxor(self, rhs: &BiMultiMap<L, R, S>) -> Self::Output {
+        let mut map = self.clone();
+        map.symmetric_difference_with(rhs);
+        map
+    }
+}
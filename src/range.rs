@@ -0,0 +1,123 @@
+use {
+    crate::{
+        BiMultiMap,
+        InverseView,
+        Key,
+        MapHasher,
+        lookup::{Lookup, lookup},
+    },
+    std::{
+        borrow::Borrow,
+        ops::{Deref, RangeBounds},
+    },
+};
+
+impl<L: Key, R: Key, S: MapHasher> BiMultiMap<L, R, S> {
+    /// Iterates over all the mappings whose left key is in `range`, in ascending order
+    ///
+    /// The bounds may be any borrowed form of `L`.
+    ///
+    /// # Panics
+    ///
+    /// Like [BTreeMap::range](std::collections::BTreeMap::range), panics if the start of `range`
+    /// is after its end, or if they are equal and both excluded.
+    pub fn range_left<Q>(&self, range: impl RangeBounds<Q>) -> impl Iterator<Item = (&L, &R)>
+    where
+        L: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        let (start, end) = (range.start_bound(), range.end_bound());
+
+        self.left_map_rc
+            .range::<dyn Lookup<Q>, _>((start.as_ref().map(lookup), end.as_ref().map(lookup)))
+            .flat_map(|(left, rights)| {
+                rights
+                    .iter()
+                    .map(move |right| (left.deref(), right.deref()))
+            })
+    }
+
+    /// Iterates over all the mappings whose right key is in `range`, in ascending order
+    ///
+    /// The bounds may be any borrowed form of `R`.
+    ///
+    /// # Panics
+    ///
+    /// Like [BTreeMap::range](std::collections::BTreeMap::range), panics if the start of `range`
+    /// is after its end, or if they are equal and both excluded.
+    pub fn range_right<Q>(&self, range: impl RangeBounds<Q>) -> impl Iterator<Item = (&L, &R)>
+    where
+        R: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        let (start, end) = (range.start_bound(), range.end_bound());
+
+        self.right_map_rc
+            .range::<dyn Lookup<Q>, _>((start.as_ref().map(lookup), end.as_ref().map(lookup)))
+            .flat_map(|(right, lefts)| lefts.iter().map(move |left| (left.deref(), right.deref())))
+    }
+
+    /// The smallest left key
+    pub fn first_left(&self) -> Option<&L> {
+        self.left_map_rc.keys().next().map(Deref::deref)
+    }
+
+    /// The biggest left key
+    pub fn last_left(&self) -> Option<&L> {
+        self.left_map_rc.keys().next_back().map(Deref::deref)
+    }
+
+    /// The smallest right key
+    pub fn first_right(&self) -> Option<&R> {
+        self.right_map_rc.keys().next().map(Deref::deref)
+    }
+
+    /// The biggest right key
+    pub fn last_right(&self) -> Option<&R> {
+        self.right_map_rc.keys().next_back().map(Deref::deref)
+    }
+}
+
+impl<'a, L: Key, R: Key, S: MapHasher> InverseView<'a, L, R, S> {
+    /// Same as [BiMultiMap::range_left]
+    pub fn range_left<Q>(&self, range: impl RangeBounds<Q>) -> impl Iterator<Item = (&'a L, &'a R)>
+    where
+        L: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        self.as_inverse()
+            .range_right(range)
+            .map(|(right, left)| (left, right))
+    }
+
+    /// Same as [BiMultiMap::range_right]
+    pub fn range_right<Q>(&self, range: impl RangeBounds<Q>) -> impl Iterator<Item = (&'a L, &'a R)>
+    where
+        R: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        self.as_inverse()
+            .range_left(range)
+            .map(|(right, left)| (left, right))
+    }
+
+    /// Same as [BiMultiMap::first_left]
+    pub fn first_left(&self) -> Option<&'a L> {
+        self.as_inverse().first_right()
+    }
+
+    /// Same as [BiMultiMap::last_left]
+    pub fn last_left(&self) -> Option<&'a L> {
+        self.as_inverse().last_right()
+    }
+
+    /// Same as [BiMultiMap::first_right]
+    pub fn first_right(&self) -> Option<&'a R> {
+        self.as_inverse().first_left()
+    }
+
+    /// Same as [BiMultiMap::last_right]
+    pub fn last_right(&self) -> Option<&'a R> {
+        self.as_inverse().last_left()
+    }
+}
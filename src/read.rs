@@ -0,0 +1,437 @@
+use {
+    crate::{
+        BiMultiMap,
+        InnerSet,
+        InverseView,
+        IterLeft,
+        IterRight,
+        Key,
+        LeftKeys,
+        MapHasher,
+        Rc,
+        RightKeys,
+    },
+    std::{borrow::Borrow, iter::FusedIterator, ops::Deref},
+};
+
+/// The read API shared by [BiMultiMap] and [InverseView]
+///
+/// Code written once against the left side of a [BiMultiMapRead] works for the right side of a
+/// [BiMultiMap] through [BiMultiMap::as_inverse], without copying the map.
+///
+/// # Example
+///
+/// ```
+/// use bimultimap::{BiMultiMap, BiMultiMapRead};
+///
+/// fn max_left_degree(map: impl BiMultiMapRead) -> usize {
+///     map.left_values()
+///         .map(|left| map.left_degree(&**left))
+///         .max()
+///         .unwrap_or(0)
+/// }
+///
+/// let map = BiMultiMap::from([('a', 0), ('a', 1), ('b', 1)]);
+///
+/// assert_eq!(max_left_degree(&map), 2);
+/// assert_eq!(max_left_degree(map.as_inverse()), 2);
+/// ```
+pub trait BiMultiMapRead {
+    type Left: Key;
+    type Right: Key;
+    type Hasher: MapHasher;
+    /// Returned by [BiMultiMapRead::iter_left]
+    type IterLeft<'a>: ExactSizeIterator<Item = &'a Self::Right> + FusedIterator + Clone
+    where
+        Self: 'a;
+    /// Returned by [BiMultiMapRead::iter_right]
+    type IterRight<'a>: ExactSizeIterator<Item = &'a Self::Left> + FusedIterator + Clone
+    where
+        Self: 'a;
+    /// Returned by [BiMultiMapRead::left_values]
+    type LeftValues<'a>: ExactSizeIterator<Item = &'a Rc<Self::Left>> + FusedIterator + Clone
+    where
+        Self: 'a;
+    /// Returned by [BiMultiMapRead::right_values]
+    type RightValues<'a>: ExactSizeIterator<Item = &'a Rc<Self::Right>> + FusedIterator + Clone
+    where
+        Self: 'a;
+
+    /// Same as [BiMultiMap::len]
+    fn len(&self) -> usize;
+
+    /// Same as [BiMultiMap::is_empty]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Same as [BiMultiMap::contains]
+    fn contains<QL, QR>(&self, left: &QL, right: &QR) -> bool
+    where
+        Self::Left: Borrow<QL>,
+        Self::Right: Borrow<QR>,
+        QL: Key + ?Sized,
+        QR: Key + ?Sized;
+
+    /// Same as [BiMultiMap::get_left]
+    fn get_left<Q>(&self, left: &Q) -> Option<&InnerSet<Rc<Self::Right>, Self::Hasher>>
+    where
+        Self::Left: Borrow<Q>,
+        Q: Key + ?Sized;
+
+    /// Same as [BiMultiMap::get_right]
+    fn get_right<Q>(&self, right: &Q) -> Option<&InnerSet<Rc<Self::Left>, Self::Hasher>>
+    where
+        Self::Right: Borrow<Q>,
+        Q: Key + ?Sized;
+
+    /// Same as [BiMultiMap::get_one_left]
+    fn get_one_left<Q>(&self, left: &Q) -> Option<&Self::Right>
+    where
+        Self::Left: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        self.iter_left(left).next()
+    }
+
+    /// Same as [BiMultiMap::get_one_right]
+    fn get_one_right<Q>(&self, right: &Q) -> Option<&Self::Left>
+    where
+        Self::Right: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        self.iter_right(right).next()
+    }
+
+    /// Same as [BiMultiMap::get_left_vec]
+    fn get_left_vec<Q>(&self, left: &Q) -> Option<Vec<&Self::Right>>
+    where
+        Self::Left: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        self.get_left(left)
+            .map(|right_set| right_set.iter().map(Deref::deref).collect())
+    }
+
+    /// Same as [BiMultiMap::get_right_vec]
+    fn get_right_vec<Q>(&self, right: &Q) -> Option<Vec<&Self::Left>>
+    where
+        Self::Right: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        self.get_right(right)
+            .map(|left_set| left_set.iter().map(Deref::deref).collect())
+    }
+
+    /// Same as [BiMultiMap::iter_left]
+    fn iter_left<Q>(&self, left: &Q) -> Self::IterLeft<'_>
+    where
+        Self::Left: Borrow<Q>,
+        Q: Key + ?Sized;
+
+    /// Same as [BiMultiMap::iter_right]
+    fn iter_right<Q>(&self, right: &Q) -> Self::IterRight<'_>
+    where
+        Self::Right: Borrow<Q>,
+        Q: Key + ?Sized;
+
+    /// Same as [BiMultiMap::contains_left]
+    fn contains_left<Q>(&self, left: &Q) -> bool
+    where
+        Self::Left: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        self.get_left(left).is_some()
+    }
+
+    /// Same as [BiMultiMap::contains_right]
+    fn contains_right<Q>(&self, right: &Q) -> bool
+    where
+        Self::Right: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        self.get_right(right).is_some()
+    }
+
+    /// Same as [BiMultiMap::left_degree]
+    fn left_degree<Q>(&self, left: &Q) -> usize
+    where
+        Self::Left: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        self.iter_left(left).len()
+    }
+
+    /// Same as [BiMultiMap::right_degree]
+    fn right_degree<Q>(&self, right: &Q) -> usize
+    where
+        Self::Right: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        self.iter_right(right).len()
+    }
+
+    /// Same as [BiMultiMap::left_len]
+    fn left_len(&self) -> usize;
+
+    /// Same as [BiMultiMap::right_len]
+    fn right_len(&self) -> usize;
+
+    /// Same as [BiMultiMap::left_values]
+    fn left_values(&self) -> Self::LeftValues<'_>;
+
+    /// Same as [BiMultiMap::right_values]
+    fn right_values(&self) -> Self::RightValues<'_>;
+}
+
+impl<L: Key, R: Key, S: MapHasher> BiMultiMapRead for BiMultiMap<L, R, S> {
+    type Hasher = S;
+    type IterLeft<'a>
+        = IterLeft<'a, R>
+    where
+        Self: 'a;
+    type IterRight<'a>
+        = IterRight<'a, L>
+    where
+        Self: 'a;
+    type Left = L;
+    type LeftValues<'a>
+        = LeftKeys<'a, L, R, S>
+    where
+        Self: 'a;
+    type Right = R;
+    type RightValues<'a>
+        = RightKeys<'a, R, L, S>
+    where
+        Self: 'a;
+
+    fn len(&self) -> usize {
+        BiMultiMap::len(self)
+    }
+
+    fn contains<QL, QR>(&self, left: &QL, right: &QR) -> bool
+    where
+        L: Borrow<QL>,
+        R: Borrow<QR>,
+        QL: Key + ?Sized,
+        QR: Key + ?Sized,
+    {
+        BiMultiMap::contains(self, left, right)
+    }
+
+    fn get_left<Q>(&self, left: &Q) -> Option<&InnerSet<Rc<R>, S>>
+    where
+        L: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        BiMultiMap::get_left(self, left)
+    }
+
+    fn get_right<Q>(&self, right: &Q) -> Option<&InnerSet<Rc<L>, S>>
+    where
+        R: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        BiMultiMap::get_right(self, right)
+    }
+
+    fn iter_left<Q>(&self, left: &Q) -> IterLeft<'_, R>
+    where
+        L: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        BiMultiMap::iter_left(self, left)
+    }
+
+    fn iter_right<Q>(&self, right: &Q) -> IterRight<'_, L>
+    where
+        R: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        BiMultiMap::iter_right(self, right)
+    }
+
+    fn left_len(&self) -> usize {
+        BiMultiMap::left_len(self)
+    }
+
+    fn right_len(&self) -> usize {
+        BiMultiMap::right_len(self)
+    }
+
+    fn left_values(&self) -> LeftKeys<'_, L, R, S> {
+        BiMultiMap::left_values(self)
+    }
+
+    fn right_values(&self) -> RightKeys<'_, R, L, S> {
+        BiMultiMap::right_values(self)
+    }
+}
+
+impl<L: Key, R: Key, S: MapHasher> BiMultiMapRead for InverseView<'_, L, R, S> {
+    type Hasher = S;
+    type IterLeft<'a>
+        = IterRight<'a, R>
+    where
+        Self: 'a;
+    type IterRight<'a>
+        = IterLeft<'a, L>
+    where
+        Self: 'a;
+    type Left = L;
+    type LeftValues<'a>
+        = RightKeys<'a, L, R, S>
+    where
+        Self: 'a;
+    type Right = R;
+    type RightValues<'a>
+        = LeftKeys<'a, R, L, S>
+    where
+        Self: 'a;
+
+    fn len(&self) -> usize {
+        InverseView::len(self)
+    }
+
+    fn contains<QL, QR>(&self, left: &QL, right: &QR) -> bool
+    where
+        L: Borrow<QL>,
+        R: Borrow<QR>,
+        QL: Key + ?Sized,
+        QR: Key + ?Sized,
+    {
+        InverseView::contains(self, left, right)
+    }
+
+    fn get_left<Q>(&self, left: &Q) -> Option<&InnerSet<Rc<R>, S>>
+    where
+        L: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        InverseView::get_left(self, left)
+    }
+
+    fn get_right<Q>(&self, right: &Q) -> Option<&InnerSet<Rc<L>, S>>
+    where
+        R: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        InverseView::get_right(self, right)
+    }
+
+    fn iter_left<Q>(&self, left: &Q) -> IterRight<'_, R>
+    where
+        L: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        InverseView::iter_left(self, left)
+    }
+
+    fn iter_right<Q>(&self, right: &Q) -> IterLeft<'_, L>
+    where
+        R: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        InverseView::iter_right(self, right)
+    }
+
+    fn left_len(&self) -> usize {
+        InverseView::left_len(self)
+    }
+
+    fn right_len(&self) -> usize {
+        InverseView::right_len(self)
+    }
+
+    fn left_values(&self) -> RightKeys<'_, L, R, S> {
+        InverseView::left_values(self)
+    }
+
+    fn right_values(&self) -> LeftKeys<'_, R, L, S> {
+        InverseView::right_values(self)
+    }
+}
+
+impl<M: BiMultiMapRead> BiMultiMapRead for &M {
+    type Hasher = M::Hasher;
+    type IterLeft<'a>
+        = M::IterLeft<'a>
+    where
+        Self: 'a;
+    type IterRight<'a>
+        = M::IterRight<'a>
+    where
+        Self: 'a;
+    type Left = M::Left;
+    type LeftValues<'a>
+        = M::LeftValues<'a>
+    where
+        Self: 'a;
+    type Right = M::Right;
+    type RightValues<'a>
+        = M::RightValues<'a>
+    where
+        Self: 'a;
+
+    fn len(&self) -> usize {
+        M::len(self)
+    }
+
+    fn contains<QL, QR>(&self, left: &QL, right: &QR) -> bool
+    where
+        M::Left: Borrow<QL>,
+        M::Right: Borrow<QR>,
+        QL: Key + ?Sized,
+        QR: Key + ?Sized,
+    {
+        M::contains(self, left, right)
+    }
+
+    fn get_left<Q>(&self, left: &Q) -> Option<&InnerSet<Rc<M::Right>, M::Hasher>>
+    where
+        M::Left: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        M::get_left(self, left)
+    }
+
+    fn get_right<Q>(&self, right: &Q) -> Option<&InnerSet<Rc<M::Left>, M::Hasher>>
+    where
+        M::Right: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        M::get_right(self, right)
+    }
+
+    fn iter_left<Q>(&self, left: &Q) -> M::IterLeft<'_>
+    where
+        M::Left: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        M::iter_left(self, left)
+    }
+
+    fn iter_right<Q>(&self, right: &Q) -> M::IterRight<'_>
+    where
+        M::Right: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        M::iter_right(self, right)
+    }
+
+    fn left_len(&self) -> usize {
+        M::left_len(self)
+    }
+
+    fn right_len(&self) -> usize {
+        M::right_len(self)
+    }
+
+    fn left_values(&self) -> M::LeftValues<'_> {
+        M::left_values(self)
+    }
+
+    fn right_values(&self) -> M::RightValues<'_> {
+        M::right_values(self)
+    }
+}
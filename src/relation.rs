@@ -0,0 +1,158 @@
+use {
+    crate::{BiMultiMap, DefaultHashBuilder, Key, LeftKeys, MapHasher, Rc, RightKeys, SetIter},
+    std::{iter::FusedIterator, ops::Deref},
+};
+
+impl<L: Key, M: Key, S: MapHasher> BiMultiMap<L, M, S> {
+    /// Composes the relation with `other`: `l` is mapped to `r` if some `m` is mapped to both
+    ///
+    /// The values are [shared](BiMultiMap#shared-values) with `self` and `other`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let groups = bimultimap::BiMultiMap::from_iter([("alice", "admin"), ("bob", "dev")]);
+    /// let permissions = bimultimap::BiMultiMap::from_iter([
+    ///     ("admin", "read"),
+    ///     ("admin", "write"),
+    ///     ("dev", "read"),
+    /// ]);
+    ///
+    /// let users = groups.compose(&permissions);
+    ///
+    /// assert_eq!(users.left_degree("alice"), 2);
+    /// assert_eq!(users.right_degree("read"), 2);
+    /// ```
+    pub fn compose<R: Key>(&self, other: &BiMultiMap<M, R, S>) -> BiMultiMap<L, R, S> {
+        let mut map = self.new_like();
+        let mut join = self.join(other);
+
+        while let Some((left, _, right)) = join.next_rc() {
+            map.insert_shared(left.clone(), right.clone());
+        }
+
+        map
+    }
+
+    /// Iterates over all the `(l, m, r)` such that `l` is mapped to `m` in `self`, and `m` to `r`
+    /// in `other`
+    ///
+    /// Only the middle values of the side with the fewest of them are traversed, the other side
+    /// being looked up.
+    pub fn join<'a, R: Key>(&'a self, other: &'a BiMultiMap<M, R, S>) -> Join<'a, L, M, R, S> {
+        let middles = if self.right_len() <= other.left_len() {
+            Middles::Left(self.right_values())
+        } else {
+            Middles::Right(other.left_values())
+        };
+
+        Join {
+            left_side: self,
+            right_side: other,
+            middles,
+            current: None,
+        }
+    }
+}
+
+/// An iterator over the values linked through two [BiMultiMap]s, obtained with
+/// [BiMultiMap::join]
+pub struct Join<'a, L: Key, M: Key, R: Key, S: MapHasher = DefaultHashBuilder> {
+    left_side:  &'a BiMultiMap<L, M, S>,
+    right_side: &'a BiMultiMap<M, R, S>,
+    middles:    Middles<'a, L, M, R, S>,
+    current:    Option<JoinCurrent<'a, L, M, R>>,
+}
+
+/// The middle values of one of the sides of a [Join]
+enum Middles<'a, L: 'a, M, R: 'a, S> {
+    Left(RightKeys<'a, M, L, S>),
+    Right(LeftKeys<'a, M, R, S>),
+}
+
+/// The middle value being joined, and the values mapped to it on each side
+struct JoinCurrent<'a, L, M, R> {
+    middle:      &'a Rc<M>,
+    left:        &'a Rc<L>,
+    lefts:       SetIter<'a, Rc<L>>,
+    rights:      SetIter<'a, Rc<R>>,
+    rights_from: SetIter<'a, Rc<R>>,
+}
+
+impl<'a, L: Key, M: Key, R: Key, S: MapHasher> Join<'a, L, M, R, S> {
+    /// Same as [Iterator::next], as the [Rc]s stored in the maps
+    pub(crate) fn next_rc(&mut self) -> Option<(&'a Rc<L>, &'a Rc<M>, &'a Rc<R>)> {
+        loop {
+            if let Some(current) = &mut self.current {
+                if let Some(right) = current.rights.next() {
+                    return Some((current.left, current.middle, right));
+                }
+
+                if let Some(left) = current.lefts.next() {
+                    current.left = left;
+                    current.rights = current.rights_from.clone();
+                    continue;
+                }
+            }
+
+            let middle = match &mut self.middles {
+                Middles::Left(middles) => middles.next()?,
+                Middles::Right(middles) => middles.next()?,
+            };
+
+            let (Some(left_set), Some(right_set)) = (
+                self.left_side.get_right(&**middle),
+                self.right_side.get_left(&**middle),
+            ) else {
+                self.current = None;
+                continue;
+            };
+
+            let mut lefts = left_set.iter();
+            let rights = right_set.iter();
+
+            self.current = lefts.next().map(|left| {
+                JoinCurrent {
+                    middle,
+                    left,
+                    lefts,
+                    rights: rights.clone(),
+                    rights_from: rights,
+                }
+            });
+        }
+    }
+}
+
+impl<'a, L: Key, M: Key, R: Key, S: MapHasher> Iterator for Join<'a, L, M, R, S> {
+    type Item = (&'a L, &'a M, &'a R);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_rc()
+            .map(|(left, middle, right)| (left.deref(), middle.deref(), right.deref()))
+    }
+}
+
+impl<L: Key, M: Key, R: Key, S: MapHasher> FusedIterator for Join<'_, L, M, R, S> {}
+
+impl<L: Key, M: Key, R: Key, S: MapHasher> Clone for Join<'_, L, M, R, S> {
+    fn clone(&self) -> Self {
+        Join {
+            left_side:  self.left_side,
+            right_side: self.right_side,
+            middles:    match &self.middles {
+                Middles::Left(middles) => Middles::Left(middles.clone()),
+                Middles::Right(middles) => Middles::Right(middles.clone()),
+            },
+            current:    self.current.as_ref().map(|current| {
+                JoinCurrent {
+                    middle:      current.middle,
+                    left:        current.left,
+                    lefts:       current.lefts.clone(),
+                    rights:      current.rights.clone(),
+                    rights_from: current.rights_from.clone(),
+                }
+            }),
+        }
+    }
+}
@@ -1,43 +1,130 @@
 //! This is a generated file! Don't modify it!!!
 use {
-    crate::{BiMultiMap, HashSet, Rc},
-    std::{borrow::Borrow, collections::hash_map::Keys, hash::Hash, ops::Deref},
+    crate::{
+        BiMultiMap,
+        DefaultHashBuilder,
+        InnerMap,
+        InnerSet,
+        Key,
+        Keys,
+        MapHasher,
+        Rc,
+        SetDiff,
+        SetIter,
+        intern,
+        intern_rc,
+        lookup::lookup,
+    },
+    std::{borrow::Borrow, iter::FusedIterator, ops::Deref},
 };
 
-impl<RightType: Hash + Eq, LeftType: Hash + Eq> BiMultiMap<LeftType, RightType> {
-    pub fn get_right(&self, right: &RightType) -> Option<&HashSet<Rc<LeftType>>> {
-        self.right_map_rc.get(right)
+impl<RightType: Key, LeftType: Key, S: MapHasher> BiMultiMap<LeftType, RightType, S> {
+    /// Gets the left values of a right key
+    ///
+    /// The key may be any borrowed form of `RightType`, like with
+    /// [HashMap::get](std::collections::HashMap::get).
+    pub fn get_right<Q>(&self, right: &Q) -> Option<&InnerSet<Rc<LeftType>, S>>
+    where
+        RightType: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        self.right_map_rc.get(lookup(&right))
     }
 
-    pub fn get_one_right(&self, right: &RightType) -> Option<&LeftType> {
+    pub fn get_one_right<Q>(&self, right: &Q) -> Option<&LeftType>
+    where
+        RightType: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
         self.get_right(right)
             .and_then(|e| e.iter().next())
             .map(Deref::deref)
     }
 
-    pub(crate) fn get_mut_right(
-        &mut self,
-        right: &RightType,
-    ) -> Option<&mut HashSet<Rc<LeftType>>> {
-        self.right_map_rc.get_mut(right)
+    /// Whether the right key is mapped to any left value
+    pub fn contains_right<Q>(&self, right: &Q) -> bool
+    where
+        RightType: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        self.right_map_rc.contains_key(lookup(&right))
     }
 
-    pub fn get_right_vec(&self, right: &RightType) -> Option<Vec<&LeftType>> {
-        self.right_map_rc
-            .get(right)
+    /// The number of left values mapped to the right key
+    pub fn right_degree<Q>(&self, right: &Q) -> usize
+    where
+        RightType: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        self.get_right(right).map_or(0, |left_set| left_set.len())
+    }
+
+    /// The number of distinct right keys
+    pub fn right_len(&self) -> usize {
+        self.right_map_rc.len()
+    }
+
+    /// The number of right keys the map can hold without reallocating
+    #[cfg(any(feature = "hashmap", feature = "hashbrown"))]
+    pub fn right_capacity(&self) -> usize {
+        self.right_map_rc.capacity()
+    }
+
+    /// Reserves room for at least `additional` more right keys
+    #[cfg(any(feature = "hashmap", feature = "hashbrown"))]
+    pub fn reserve_right(&mut self, additional: usize) {
+        self.right_map_rc.reserve(additional);
+    }
+
+    pub(crate) fn get_mut_right<Q>(&mut self, right: &Q) -> Option<&mut InnerSet<Rc<LeftType>, S>>
+    where
+        RightType: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        self.right_map_rc.get_mut(lookup(&right))
+    }
+
+    pub fn get_right_vec<Q>(&self, right: &Q) -> Option<Vec<&LeftType>>
+    where
+        RightType: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        self.get_right(right)
             .map(|map| map.iter().map(Deref::deref).collect())
     }
 
-    pub fn remove_right<RightRef: Borrow<RightType>>(
-        &mut self,
-        right: RightRef,
-    ) -> Option<HashSet<Rc<LeftType>>> {
-        let right = right.borrow();
-        match self.right_map_rc.remove(right) {
+    /// Iterates over the left values of a right key, without allocating
+    ///
+    /// The iterator is empty if the key isn't in the map.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let map = bimultimap::BiMultiMap::from_iter([(0, 0), (0, 1), (1, 0)]);
+    ///
+    /// assert_eq!(map.iter_right(&0).len(), 2);
+    /// assert_eq!(map.iter_right(&2).next(), None);
+    /// ```
+    pub fn iter_right<Q>(&self, right: &Q) -> IterRight<'_, LeftType>
+    where
+        RightType: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        IterRight {
+            left_values: self.get_right(right).map(|left_set| left_set.iter()),
+        }
+    }
+
+    pub fn remove_right<Q>(&mut self, right: &Q) -> Option<InnerSet<Rc<LeftType>, S>>
+    where
+        RightType: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        match self.right_map_rc.remove(lookup(&right)) {
             Some(left_set) => {
                 left_set.iter().for_each(|left| {
-                    let is_empty = self.get_mut_left(left).map(|hashet_right| {
-                        hashet_right.remove(right);
+                    let is_empty = self.get_mut_left(&**left).map(|hashet_right| {
+                        hashet_right.remove(lookup(&right));
 
                         hashet_right.is_empty()
                     });
@@ -55,12 +142,97 @@ impl<RightType: Hash + Eq, LeftType: Hash + Eq> BiMultiMap<LeftType, RightType>
         }
     }
 
+    /// Maps the right key to all of `left_values`
+    ///
+    /// Unlike calling [BiMultiMap::insert] for each value, the right key is only looked up once.
+    ///
+    /// Returns the number of mappings added.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut map = bimultimap::BiMultiMap::from_iter([(0, 0)]);
+    ///
+    /// assert_eq!(map.extend_right(0, [0, 1, 1]), 1);
+    /// assert_eq!(map.len(), 2);
+    /// ```
+    pub fn extend_right<I: IntoIterator<Item = LeftType>>(
+        &mut self,
+        right: RightType,
+        left_values: I,
+    ) -> usize {
+        let right_rc = intern(&self.right_map_rc, right);
+        let left_set = self.right_map_rc.entry(right_rc.clone()).or_default();
+        let mut added = 0usize;
+
+        for left in left_values {
+            let left_rc = intern(&self.left_map_rc, left);
+
+            if left_set.insert(left_rc.clone()) {
+                self.left_map_rc
+                    .entry(left_rc)
+                    .or_default()
+                    .insert(right_rc.clone());
+
+                added += 1;
+            }
+        }
+
+        // Nothing to map the key to, so it mustn't stay with an empty set
+        if left_set.is_empty() {
+            self.right_map_rc.remove(&right_rc);
+        }
+
+        self.len += added;
+
+        added
+    }
+
+    /// Only keeps the right keys for which `f` returns `true`, given their left values
+    pub fn retain_right<F: FnMut(&RightType, &InnerSet<Rc<LeftType>, S>) -> bool>(
+        &mut self,
+        mut f: F,
+    ) {
+        let mut removed = Vec::new();
+
+        self.right_map_rc.retain(|right, left_set| {
+            let should_keep = f(right, left_set);
+
+            if !should_keep {
+                removed.push((right.clone(), std::mem::take(left_set)));
+            }
+
+            should_keep
+        });
+
+        for (right, left_set) in removed {
+            self.len -= left_set.len();
+
+            for left in left_set {
+                let should_remove_left = self.left_map_rc.get_mut(&left).map(|right_set| {
+                    right_set.remove(&right);
+                    right_set.is_empty()
+                });
+
+                if should_remove_left == Some(true) {
+                    self.left_map_rc.remove(&left);
+                }
+            }
+        }
+    }
+
     // Setter
     /// Set a left_value by the right key
     ///
     /// The argument passed is an [Rc] for the `right_key`, since it might be inserted as a value
     /// for all the left_values that are in `left_values` but not present in the [`BiMultiMap`]
-    pub fn set_right(&mut self, right_key: Rc<RightType>, left_values: HashSet<LeftType>) {
+    ///
+    /// Returns the left values that were added and removed for this key.
+    pub fn set_right(
+        &mut self,
+        right_key: Rc<RightType>,
+        left_values: InnerSet<LeftType, S>,
+    ) -> SetDiff<LeftType, S> {
         // These 2 declarations, demonstrate perfectly what this function does.
         //
         // We have on one side what Ill call `bmm_left_values` (BiMultiMapLeftValues) and
@@ -73,10 +245,12 @@ impl<RightType: Hash + Eq, LeftType: Hash + Eq> BiMultiMap<LeftType, RightType>
         //    in both. No need to modify anything.
         //  3. If a value of `left_values` is not in `bmm_left_values` => We want to add it to
         //     `bmm_left_values`. This is `to_add_as_value_of_left`.
-        let mut to_add_as_value_of_left = HashSet::new();
-        let mut to_be_removed = HashSet::new();
+        let mut to_add_as_value_of_left = InnerSet::<_, S>::default();
+        let mut to_be_removed = InnerSet::<_, S>::default();
+
+        let right_key = intern_rc(&self.right_map_rc, right_key);
 
-        match self.get_mut_right(&right_key) {
+        match self.right_map_rc.get_mut(&right_key) {
             Some(left_set) => {
                 // Check for value to remove
                 for left_value in left_set.iter() {
@@ -89,33 +263,41 @@ impl<RightType: Hash + Eq, LeftType: Hash + Eq> BiMultiMap<LeftType, RightType>
                 // Check for value to add
                 for left_value in left_values {
                     if !left_set.contains(&left_value) {
-                        let left_rc = Rc::new(left_value);
+                        let left_rc = intern(&self.left_map_rc, left_value);
                         to_add_as_value_of_left.insert(left_rc.clone());
                         left_set.insert(left_rc);
                     }
                 }
             },
+            // Nothing to map the key to, so it mustn't be inserted with an empty set
+            None if left_values.is_empty() => {},
             None => {
-                let rc_values: HashSet<_> = left_values.into_iter().map(Rc::new).collect();
-                // Ok to clone since it's an `HashSet` of `Rc`
+                let rc_values: InnerSet<_, S> = left_values
+                    .into_iter()
+                    .map(|left_value| intern(&self.left_map_rc, left_value))
+                    .collect();
+                // Ok to clone since it's a `Set` of `Rc`
                 let rc_values_cloned = rc_values.clone();
                 self.right_map_rc.insert(right_key.clone(), rc_values);
                 to_add_as_value_of_left.extend(rc_values_cloned);
             },
         }
 
-        for left_key in to_add_as_value_of_left {
+        self.len += to_add_as_value_of_left.len();
+        self.len -= to_be_removed.len();
+
+        for left_key in to_add_as_value_of_left.iter() {
             self.left_map_rc
                 .entry(left_key.clone())
                 .and_modify(|right_set| {
                     right_set.insert(right_key.clone());
                 })
-                .or_insert_with(|| HashSet::from_iter([right_key.clone()]));
+                .or_insert_with(|| InnerSet::<_, S>::from_iter([right_key.clone()]));
         }
 
-        for left_key in to_be_removed {
-            if let Some(left_set_mut) = self.get_mut_right(&right_key) {
-                left_set_mut.remove(&*left_key);
+        for left_key in to_be_removed.iter() {
+            if let Some(left_set_mut) = self.get_mut_right(&*right_key) {
+                left_set_mut.remove(&**left_key);
             }
 
             self.left_map_rc
@@ -126,23 +308,355 @@ impl<RightType: Hash + Eq, LeftType: Hash + Eq> BiMultiMap<LeftType, RightType>
 
             if self
                 .left_map_rc
-                .get(&left_key)
+                .get(left_key)
                 .is_some_and(|right_set| right_set.is_empty())
             {
-                self.left_map_rc.remove(&left_key);
+                self.left_map_rc.remove(left_key);
+            }
+        }
+
+        if self
+            .get_right(&*right_key)
+            .is_some_and(|left_set| left_set.is_empty())
+        {
+            self.right_map_rc.remove(&right_key);
+        }
+
+        SetDiff {
+            added:   to_add_as_value_of_left,
+            removed: to_be_removed,
+        }
+    }
+
+    /// Iterates over all the distinct right keys
+    pub fn right_values(&self) -> RightKeys<'_, RightType, LeftType, S> {
+        RightKeys {
+            keys: self.right_map_rc.keys(),
+        }
+    }
+
+    /// Consumes the map into a map from each right key to its left values
+    ///
+    /// Values are moved out of their [Rc] when possible: left values are only cloned when they
+    /// are mapped to several right keys, and keys only when they are shared with a clone of the
+    /// map.
+    pub fn into_right_map(self) -> InnerMap<RightType, InnerSet<LeftType, S>, S>
+    where
+        RightType: Clone,
+        LeftType: Clone,
+    {
+        self.into_right_map_rc()
+            .into_iter()
+            .map(|(right, left_set)| {
+                (
+                    Rc::unwrap_or_clone(right),
+                    left_set.into_iter().map(Rc::unwrap_or_clone).collect(),
+                )
+            })
+            .collect()
+    }
+
+    /// Drops the left map, so that the right map holds the only [Rc] of each right key, unless the
+    /// map was cloned
+    pub(crate) fn into_right_map_rc(self) -> InnerMap<Rc<RightType>, InnerSet<Rc<LeftType>, S>, S> {
+        self.right_map_rc
+    }
+
+    /// Gets the entry of a right key, to inspect or modify its left values in place
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut map = bimultimap::BiMultiMap::new();
+    ///
+    /// map.right_entry('a').insert_value(0).insert_value(1);
+    /// map.right_entry('a').and_modify(|entry| {
+    ///     entry.remove_value(&0);
+    /// });
+    ///
+    /// assert_eq!(map.get_right_vec(&'a'), Some(vec![&1]));
+    /// assert_eq!(map.get_left_vec(&0), None);
+    /// assert_eq!(map.len(), 1);
+    /// ```
+    pub fn right_entry(&mut self, right: RightType) -> RightEntry<'_, RightType, LeftType, S> {
+        match self
+            .right_map_rc
+            .get_key_value(&right)
+            .map(|(right_rc, _)| right_rc.clone())
+        {
+            Some(key) => RightEntry::Occupied(OccupiedRightEntry { map: self, key }),
+            None => {
+                RightEntry::Vacant(VacantRightEntry {
+                    map: self,
+                    key: right,
+                })
+            },
+        }
+    }
+}
+
+/// An iterator over the left values of a right key, obtained with [BiMultiMap::iter_right]
+pub struct IterRight<'a, LeftType> {
+    left_values: Option<SetIter<'a, Rc<LeftType>>>,
+}
+
+impl<'a, LeftType> Iterator for IterRight<'a, LeftType> {
+    type Item = &'a LeftType;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.left_values.as_mut()?.next().map(Deref::deref)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.left_values
+            .as_ref()
+            .map_or((0, Some(0)), Iterator::size_hint)
+    }
+}
+
+impl<LeftType> ExactSizeIterator for IterRight<'_, LeftType> {}
+
+impl<LeftType> FusedIterator for IterRight<'_, LeftType> {}
+
+impl<LeftType> Clone for IterRight<'_, LeftType> {
+    fn clone(&self) -> Self {
+        IterRight {
+            left_values: self.left_values.clone(),
+        }
+    }
+}
+
+/// An iterator over the right keys of a [BiMultiMap], obtained with [BiMultiMap::right_values]
+pub struct RightKeys<'a, RightType, LeftType: 'a, S = DefaultHashBuilder> {
+    keys: Keys<'a, Rc<RightType>, InnerSet<Rc<LeftType>, S>>,
+}
+
+impl<'a, RightType, LeftType, S> Iterator for RightKeys<'a, RightType, LeftType, S> {
+    type Item = &'a Rc<RightType>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.keys.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.keys.size_hint()
+    }
+}
+
+impl<RightType, LeftType, S> ExactSizeIterator for RightKeys<'_, RightType, LeftType, S> {}
+
+impl<RightType, LeftType, S> FusedIterator for RightKeys<'_, RightType, LeftType, S> {}
+
+impl<RightType, LeftType, S> Clone for RightKeys<'_, RightType, LeftType, S> {
+    fn clone(&self) -> Self {
+        RightKeys {
+            keys: self.keys.clone(),
+        }
+    }
+}
+
+/// A view into a single right key of a [BiMultiMap], obtained with [BiMultiMap::right_entry]
+pub enum RightEntry<'a, RightType: Key, LeftType: Key, S: MapHasher = DefaultHashBuilder> {
+    Occupied(OccupiedRightEntry<'a, RightType, LeftType, S>),
+    Vacant(VacantRightEntry<'a, RightType, LeftType, S>),
+}
+
+impl<'a, RightType: Key, LeftType: Key, S: MapHasher> RightEntry<'a, RightType, LeftType, S> {
+    pub fn key(&self) -> &RightType {
+        match self {
+            RightEntry::Occupied(entry) => entry.key(),
+            RightEntry::Vacant(entry) => entry.key(),
+        }
+    }
+
+    /// If the key is vacant, maps it to all the left values returned by `default`
+    ///
+    /// The entry stays vacant if `default` returns an empty set.
+    pub fn or_insert_with_set<F: FnOnce() -> InnerSet<LeftType, S>>(self, default: F) -> Self {
+        match self {
+            RightEntry::Occupied(entry) => RightEntry::Occupied(entry),
+            RightEntry::Vacant(entry) => {
+                let left_values = default();
+
+                if left_values.is_empty() {
+                    RightEntry::Vacant(entry)
+                } else {
+                    RightEntry::Occupied(entry.insert_value_set(left_values))
+                }
+            },
+        }
+    }
+
+    /// Maps the key to `left`, whether the entry is occupied or not
+    pub fn insert_value(self, left: LeftType) -> OccupiedRightEntry<'a, RightType, LeftType, S> {
+        match self {
+            RightEntry::Occupied(mut entry) => {
+                entry.insert_value(left);
+                entry
+            },
+            RightEntry::Vacant(entry) => entry.insert_value(left),
+        }
+    }
+
+    /// Calls `f` on the entry if it's occupied
+    pub fn and_modify<F: FnOnce(&mut OccupiedRightEntry<'a, RightType, LeftType, S>)>(
+        self,
+        f: F,
+    ) -> Self {
+        match self {
+            RightEntry::Occupied(mut entry) => {
+                f(&mut entry);
+                RightEntry::Occupied(entry)
+            },
+            RightEntry::Vacant(entry) => RightEntry::Vacant(entry),
+        }
+    }
+
+    /// Removes the key and all its mappings, returning its left values if it was occupied
+    pub fn remove_all(self) -> Option<InnerSet<Rc<LeftType>, S>> {
+        match self {
+            RightEntry::Occupied(entry) => Some(entry.remove_all()),
+            RightEntry::Vacant(_) => None,
+        }
+    }
+}
+
+/// A right key present in a [BiMultiMap]
+///
+/// If all its left values are removed, the key is removed from the map when the entry is
+/// dropped.
+pub struct OccupiedRightEntry<'a, RightType: Key, LeftType: Key, S: MapHasher = DefaultHashBuilder>
+{
+    map: &'a mut BiMultiMap<LeftType, RightType, S>,
+    key: Rc<RightType>,
+}
+
+impl<RightType: Key, LeftType: Key, S: MapHasher> OccupiedRightEntry<'_, RightType, LeftType, S> {
+    pub fn key(&self) -> &RightType {
+        &self.key
+    }
+
+    /// The left values currently mapped to the key
+    pub fn get(&self) -> &InnerSet<Rc<LeftType>, S> {
+        &self.map.right_map_rc[&self.key]
+    }
+
+    /// Maps the key to `left`
+    ///
+    /// Returns whether the mapping was added.
+    pub fn insert_value(&mut self, left: LeftType) -> bool {
+        let left_rc = intern(&self.map.left_map_rc, left);
+
+        let was_added = self
+            .map
+            .right_map_rc
+            .get_mut(&self.key)
+            .is_some_and(|left_set| left_set.insert(left_rc.clone()));
+
+        if was_added {
+            self.map
+                .left_map_rc
+                .entry(left_rc)
+                .and_modify(|right_set| {
+                    right_set.insert(self.key.clone());
+                })
+                .or_insert_with(|| InnerSet::<_, S>::from_iter([self.key.clone()]));
+
+            self.map.len += 1;
+        }
+
+        was_added
+    }
+
+    /// Removes the mapping between the key and `left`
+    ///
+    /// Returns whether the mapping was removed.
+    pub fn remove_value<Q>(&mut self, left: &Q) -> bool
+    where
+        LeftType: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        let was_removed = self
+            .map
+            .right_map_rc
+            .get_mut(&self.key)
+            .is_some_and(|left_set| left_set.remove(lookup(&left)));
+
+        if was_removed {
+            let should_remove_left = self.map.get_mut_left(left).map(|right_set| {
+                right_set.remove(&self.key);
+                right_set.is_empty()
+            });
+
+            if should_remove_left == Some(true) {
+                self.map.left_map_rc.remove(lookup(&left));
             }
+
+            self.map.len -= 1;
         }
+
+        was_removed
+    }
+
+    /// Removes the key and all its mappings, returning its left values
+    pub fn remove_all(self) -> InnerSet<Rc<LeftType>, S> {
+        self.map.remove_right(&*self.key).unwrap_or_default()
+    }
+}
+
+impl<RightType: Key, LeftType: Key, S: MapHasher> Drop
+    for OccupiedRightEntry<'_, RightType, LeftType, S>
+{
+    fn drop(&mut self) {
+        if self
+            .map
+            .right_map_rc
+            .get(&self.key)
+            .is_some_and(|left_set| left_set.is_empty())
+        {
+            self.map.right_map_rc.remove(&self.key);
+        }
+    }
+}
+
+/// A right key absent from a [BiMultiMap]
+pub struct VacantRightEntry<'a, RightType: Key, LeftType: Key, S: MapHasher = DefaultHashBuilder> {
+    map: &'a mut BiMultiMap<LeftType, RightType, S>,
+    key: RightType,
+}
+
+impl<'a, RightType: Key, LeftType: Key, S: MapHasher> VacantRightEntry<'a, RightType, LeftType, S> {
+    pub fn key(&self) -> &RightType {
+        &self.key
     }
 
-    pub fn right_values(&self) -> Keys<Rc<RightType>, HashSet<Rc<LeftType>>> {
-        self.right_map_rc.keys()
+    pub fn into_key(self) -> RightType {
+        self.key
     }
 
-    // pub fn right_entry(
-    //     &mut self,
-    //     right_entry: Rc<RightType>,
-    // ) -> std::collections::hash_map::Entry<'_, std::rc::Rc<RightType>, HashSet<std::rc::Rc<LeftType>>>
-    // {
-    //     self.right_map_rc.entry(right_entry)
-    // }
+    /// Maps the key to `left`
+    pub fn insert_value(self, left: LeftType) -> OccupiedRightEntry<'a, RightType, LeftType, S> {
+        let mut entry = self.insert_value_set(InnerSet::<_, S>::default());
+        entry.insert_value(left);
+        entry
+    }
+
+    fn insert_value_set(
+        self,
+        left_values: InnerSet<LeftType, S>,
+    ) -> OccupiedRightEntry<'a, RightType, LeftType, S> {
+        let key = Rc::new(self.key);
+
+        self.map
+            .right_map_rc
+            .insert(key.clone(), InnerSet::<_, S>::default());
+
+        let mut entry = OccupiedRightEntry { map: self.map, key };
+
+        for left in left_values {
+            entry.insert_value(left);
+        }
+
+        entry
+    }
 }
@@ -1,22 +1,77 @@
+//! Serde support
+//!
+//! [BiMultiMap] implements [Serialize] and [Deserialize] as a map with one entry per mapping,
+//! which means repeated keys as soon as a value has several partners. Many formats reject or
+//! collapse those, so these modules offer other representations, to use with
+//! `#[serde(with = "...")]`:
+//!
+//! - [grouped]: `{"a": [0, 1], "b": [1]}`, a map from each left value to its right values
+//! - [grouped_seq]: `[["a", [0, 1]], ["b", [1]]]`, the same as a sequence
+//! - [pairs]: `[["a", 0], ["a", 1], ["b", 1]]`, a sequence of mappings
+//! - [two_maps]: `{"left": {"a": [0, 1], "b": [1]}, "right": {"0": ["a"], "1": ["a", "b"]}}`,
+//!   both sides grouped
+//!
+//! ```
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Permissions {
+//!     #[serde(with = "bimultimap::serde::grouped")]
+//!     groups: bimultimap::BiMultiMap<String, u8>,
+//! }
+//! ```
+//!
+//! Deserializing [BiMultiMap] also accepts a sequence of `[left, right]` pairs in human-readable
+//! formats, and keeps a mapping that appears twice once. [strict] rejects those instead. Values
+//! that borrow from the input, like `&str`, are deserialized without copying.
+//!
+//! To tell a map from a sequence, human-readable formats are asked for any type with
+//! `deserialize_any` rather than for a map, which fails with the human-readable formats that
+//! aren't self-describing.
+//!
+//! The deserialization of the other representations rejects anything their serialization can't
+//! produce, like a value mapped to an empty sequence, or the same mapping twice.
+//!
+//! Errors name the side and the index of the value they are about, like `invalid type: string
+//! "zero", expected u8 as the right value of the mapping at index 1`.
+//!
+//! All of them follow the iteration order of the map, which depends on the insertion order and on
+//! the hasher with `hashmap` and `hashbrown`. [sorted] has their sorted versions, for an output
+//! that only depends on the content of the map.
+//!
+//! Formats like JSON only accept strings as map keys. With values like tuples or structs, use
+//! [pairs] or [grouped_seq], which don't serialize any value as a map key.
+
+mod context;
+pub mod grouped;
+pub mod grouped_seq;
+pub mod pairs;
+pub mod sorted;
+pub mod strict;
+pub mod two_maps;
+
 use {
-    crate::BiMultiMap,
+    crate::{BiMultiMap, Key, MapHasher},
+    context::{Context, InContext, Pair, Role},
     serde::{
         Deserialize,
         Serialize,
-        de::{MapAccess, Visitor},
+        de::{Error, MapAccess, SeqAccess, Visitor},
         ser::SerializeMap,
     },
-    std::{borrow::Borrow, hash::Hash, marker::PhantomData},
+    std::{borrow::Borrow, marker::PhantomData},
 };
 
-impl<L, R> Serialize for BiMultiMap<L, R>
+/// Makes the visitors generic over the map they build
+type Marker<L, R, S> = PhantomData<fn() -> BiMultiMap<L, R, S>>;
+
+impl<L, R, S> Serialize for BiMultiMap<L, R, S>
 where
-    L: Hash + Eq + Serialize,
-    R: Hash + Eq + Serialize,
+    L: Key + Serialize,
+    R: Key + Serialize,
+    S: MapHasher,
 {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    fn serialize<Ser>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error>
     where
-        S: serde::Serializer,
+        Ser: serde::Serializer,
     {
         let mut seq = serializer.serialize_map(Some(self.len()))?;
         for (left, right) in self.iter() {
@@ -26,50 +81,136 @@ where
     }
 }
 
-impl<'de, L, R> serde::Deserialize<'de> for BiMultiMap<L, R>
+impl<'de, L, R, S> serde::Deserialize<'de> for BiMultiMap<L, R, S>
 where
-    L: Deserialize<'de> + Hash + Eq,
-    R: Deserialize<'de> + Hash + Eq,
+    L: Deserialize<'de> + Key,
+    R: Deserialize<'de> + Key,
+    S: MapHasher,
 {
+    /// Accepts a map with one entry per mapping, or a sequence of `[left, right]` pairs in
+    /// human-readable formats, keeping the repeated mappings once
+    ///
+    /// Human-readable formats are asked for any type with
+    /// [deserialize_any](serde::Deserializer::deserialize_any), and the others for a map.
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
-        deserializer.deserialize_map(BiMultiMapVisitor::new())
+        deserialize_flat(deserializer, false)
+    }
+}
+
+/// Deserializes the default representation, failing on repeated mappings if `reject_duplicates`
+///
+/// Only human-readable formats are asked for any type, since compact ones like `bincode` can't
+/// tell a map from a sequence.
+fn deserialize_flat<'de, L, R, S, D>(
+    deserializer: D,
+    reject_duplicates: bool,
+) -> Result<BiMultiMap<L, R, S>, D::Error>
+where
+    L: Deserialize<'de> + Key,
+    R: Deserialize<'de> + Key,
+    S: MapHasher,
+    D: serde::Deserializer<'de>,
+{
+    let visitor = BiMultiMapVisitor {
+        marker: PhantomData,
+        reject_duplicates,
+    };
+
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(visitor)
+    } else {
+        deserializer.deserialize_map(visitor)
     }
 }
 
-struct BiMultiMapVisitor<L: Hash + Eq, R: Hash + Eq> {
-    marker: PhantomData<fn() -> BiMultiMap<L, R>>,
+/// The most mappings preallocated from a size hint, which comes from the input and can't be trusted
+#[cfg(any(feature = "hashmap", feature = "hashbrown"))]
+const MAX_PREALLOCATED: usize = 4096;
+
+/// An empty map with room for the `size_hint` mappings of a map or sequence being deserialized
+#[cfg(any(feature = "hashmap", feature = "hashbrown"))]
+fn with_size_hint<L: Key, R: Key, S: MapHasher>(size_hint: Option<usize>) -> BiMultiMap<L, R, S> {
+    let capacity = size_hint.unwrap_or(0).min(MAX_PREALLOCATED);
+
+    BiMultiMap::with_capacity_and_hasher(capacity, capacity, S::default())
+}
+
+/// An empty map with room for the `size_hint` mappings of a map or sequence being deserialized
+#[cfg(feature = "btreemap")]
+fn with_size_hint<L: Key, R: Key, S: MapHasher>(_size_hint: Option<usize>) -> BiMultiMap<L, R, S> {
+    BiMultiMap::default()
+}
+
+struct BiMultiMapVisitor<L: Key, R: Key, S: MapHasher> {
+    marker:            Marker<L, R, S>,
+    reject_duplicates: bool,
 }
 
-impl<L: Hash + Eq, R: Hash + Eq> BiMultiMapVisitor<L, R> {
-    fn new() -> Self {
-        BiMultiMapVisitor {
-            marker: PhantomData,
+impl<L: Key, R: Key, S: MapHasher> BiMultiMapVisitor<L, R, S> {
+    /// Inserts the mapping at `index` in the input
+    fn insert<E: Error>(
+        &self,
+        map: &mut BiMultiMap<L, R, S>,
+        index: usize,
+        left: L,
+        right: R,
+    ) -> Result<(), E> {
+        if !map.insert(left, right) && self.reject_duplicates {
+            return Err(E::custom(format_args!(
+                "the left and right values of the mapping at index {index} are already mapped to \
+                 each other"
+            )));
         }
+
+        Ok(())
     }
 }
 
-impl<'de, L, R> Visitor<'de> for BiMultiMapVisitor<L, R>
+impl<'de, L, R, S> Visitor<'de> for BiMultiMapVisitor<L, R, S>
 where
-    L: Deserialize<'de> + Hash + Eq,
-    R: Deserialize<'de> + Hash + Eq,
+    L: Deserialize<'de> + Key,
+    R: Deserialize<'de> + Key,
+    S: MapHasher,
 {
-    type Value = BiMultiMap<L, R>;
+    type Value = BiMultiMap<L, R, S>;
 
     fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-        formatter.write_str("a very special map")
+        formatter.write_str(
+            "a map from left values to right values, or a sequence of [left, right] pairs",
+        )
     }
 
     fn visit_map<M>(self, mut access: M) -> Result<Self::Value, M::Error>
     where
         M: MapAccess<'de>,
     {
-        let mut map = BiMultiMap::new();
+        let mut map = with_size_hint(access.size_hint());
+        let mut index = 0;
+
+        while let Some((left, right)) = access.next_entry_seed(
+            InContext::new(Context::mapping(Role::Left, index)),
+            InContext::new(Context::mapping(Role::Right, index)),
+        )? {
+            self.insert(&mut map, index, left, right)?;
+            index += 1;
+        }
+
+        Ok(map)
+    }
+
+    fn visit_seq<A>(self, mut access: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut map = with_size_hint(access.size_hint());
+        let mut index = 0;
 
-        while let Some((left, right)) = access.next_entry()? {
-            map.insert(left, right);
+        while let Some((left, right)) = access.next_element_seed(Pair::mapping(index))? {
+            self.insert(&mut map, index, left, right)?;
+            index += 1;
         }
 
         Ok(map)
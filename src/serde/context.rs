@@ -0,0 +1,382 @@
+//! Seeds naming the side and the position of a value in the errors about its type, like
+//! `invalid type: string "zero", expected u8 as the right value of the mapping at index 1`
+//!
+//! The context goes in what the visitor expects rather than in a new error, since formats like
+//! JSON add the location to the message of the errors raised while deserializing.
+
+use {
+    serde::{
+        Deserialize,
+        Deserializer,
+        de::{DeserializeSeed, EnumAccess, Error, MapAccess, SeqAccess, Visitor},
+    },
+    std::{
+        fmt::{self, Display, Formatter},
+        marker::PhantomData,
+    },
+};
+
+/// The side of a mapping a value belongs to
+#[derive(Clone, Copy)]
+pub(crate) enum Role {
+    Left,
+    Right,
+}
+
+impl Role {
+    /// The side of the partners of a value
+    pub(crate) fn other(self) -> Role {
+        match self {
+            Role::Left => Role::Right,
+            Role::Right => Role::Left,
+        }
+    }
+}
+
+impl Display for Role {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str(match self {
+            Role::Left => "left",
+            Role::Right => "right",
+        })
+    }
+}
+
+/// Where a value is in the input: its side, in the mapping or group at an index
+#[derive(Clone, Copy)]
+pub(crate) struct Context {
+    role:  Role,
+    of:    &'static str,
+    index: usize,
+}
+
+impl Context {
+    /// A value of the mapping at `index`
+    pub(crate) fn mapping(role: Role, index: usize) -> Self {
+        Context {
+            role,
+            of: "mapping",
+            index,
+        }
+    }
+
+    /// A value of the group at `index`, a key with its partners
+    pub(crate) fn group(role: Role, index: usize) -> Self {
+        Context {
+            role,
+            of: "group",
+            index,
+        }
+    }
+}
+
+impl Display for Context {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "the {} value of the {} at index {}",
+            self.role, self.of, self.index
+        )
+    }
+}
+
+/// Deserializes a `T` in a [Context]
+pub(crate) struct InContext<T> {
+    context: Context,
+    marker:  PhantomData<fn() -> T>,
+}
+
+impl<T> InContext<T> {
+    pub(crate) fn new(context: Context) -> Self {
+        InContext {
+            context,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>> DeserializeSeed<'de> for InContext<T> {
+    type Value = T;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<T, D::Error> {
+        T::deserialize(ContextDeserializer {
+            deserializer,
+            context: self.context,
+        })
+    }
+}
+
+/// Deserializes the sequence of partners of a group, each in a [Context]
+pub(crate) struct GroupInContext<T> {
+    context: Context,
+    marker:  PhantomData<fn() -> T>,
+}
+
+impl<T> GroupInContext<T> {
+    pub(crate) fn new(context: Context) -> Self {
+        GroupInContext {
+            context,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>> DeserializeSeed<'de> for GroupInContext<T> {
+    type Value = Vec<T>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Vec<T>, D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for GroupInContext<T> {
+    type Value = Vec<T>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "a sequence of {} values for the group at index {}",
+            self.context.role, self.context.index
+        )
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut access: A) -> Result<Vec<T>, A::Error> {
+        let mut values = Vec::new();
+
+        while let Some(value) = access.next_element_seed(InContext::new(self.context))? {
+            values.push(value);
+        }
+
+        Ok(values)
+    }
+}
+
+/// Deserializes a pair with two seeds, like `[left, right]` or `[left, [right...]]`
+pub(crate) struct Pair<A, B> {
+    first:  A,
+    second: B,
+    index:  usize,
+}
+
+impl<L, R> Pair<InContext<L>, InContext<R>> {
+    /// The `[left, right]` pair of the mapping at `index`
+    pub(crate) fn mapping(index: usize) -> Self {
+        Pair {
+            first: InContext::new(Context::mapping(Role::Left, index)),
+            second: InContext::new(Context::mapping(Role::Right, index)),
+            index,
+        }
+    }
+}
+
+impl<L, R> Pair<InContext<L>, GroupInContext<R>> {
+    /// The `[left, [right...]]` pair of the group at `index`
+    pub(crate) fn group(index: usize) -> Self {
+        Pair {
+            first: InContext::new(Context::group(Role::Left, index)),
+            second: GroupInContext::new(Context::group(Role::Right, index)),
+            index,
+        }
+    }
+}
+
+impl<'de, A: DeserializeSeed<'de>, B: DeserializeSeed<'de>> DeserializeSeed<'de> for Pair<A, B> {
+    type Value = (A::Value, B::Value);
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_tuple(2, self)
+    }
+}
+
+impl<'de, A: DeserializeSeed<'de>, B: DeserializeSeed<'de>> Visitor<'de> for Pair<A, B> {
+    type Value = (A::Value, B::Value);
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "a pair at index {}", self.index)
+    }
+
+    fn visit_seq<S: SeqAccess<'de>>(self, mut access: S) -> Result<Self::Value, S::Error> {
+        let missing = |len| {
+            let expected = format!("a pair at index {}", self.index);
+            S::Error::invalid_length(len, &expected.as_str())
+        };
+        let first = access
+            .next_element_seed(self.first)?
+            .ok_or_else(|| missing(0))?;
+        let second = access
+            .next_element_seed(self.second)?
+            .ok_or_else(|| missing(1))?;
+
+        Ok((first, second))
+    }
+}
+
+/// Passes a [ContextVisitor] to the wrapped deserializer, whatever the type asked for
+struct ContextDeserializer<D> {
+    deserializer: D,
+    context:      Context,
+}
+
+impl<D> ContextDeserializer<D> {
+    fn visitor<V>(&self, visitor: V) -> ContextVisitor<V> {
+        ContextVisitor {
+            visitor,
+            context: self.context,
+        }
+    }
+}
+
+/// Forwards the `deserialize_*` methods taking only a visitor
+macro_rules! forward_deserialize {
+    ($($method:ident)*) => {$(
+        fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, D::Error> {
+            let visitor = self.visitor(visitor);
+            self.deserializer.$method(visitor)
+        }
+    )*};
+}
+
+impl<'de, D: Deserializer<'de>> Deserializer<'de> for ContextDeserializer<D> {
+    type Error = D::Error;
+
+    forward_deserialize! {
+        deserialize_any deserialize_bool
+        deserialize_i8 deserialize_i16 deserialize_i32 deserialize_i64 deserialize_i128
+        deserialize_u8 deserialize_u16 deserialize_u32 deserialize_u64 deserialize_u128
+        deserialize_f32 deserialize_f64 deserialize_char deserialize_str deserialize_string
+        deserialize_bytes deserialize_byte_buf deserialize_option deserialize_unit
+        deserialize_seq deserialize_map deserialize_identifier deserialize_ignored_any
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, D::Error> {
+        let visitor = self.visitor(visitor);
+        self.deserializer.deserialize_unit_struct(name, visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, D::Error> {
+        let visitor = self.visitor(visitor);
+        self.deserializer.deserialize_newtype_struct(name, visitor)
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, D::Error> {
+        let visitor = self.visitor(visitor);
+        self.deserializer.deserialize_tuple(len, visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, D::Error> {
+        let visitor = self.visitor(visitor);
+        self.deserializer
+            .deserialize_tuple_struct(name, len, visitor)
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, D::Error> {
+        let visitor = self.visitor(visitor);
+        self.deserializer.deserialize_struct(name, fields, visitor)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, D::Error> {
+        let visitor = self.visitor(visitor);
+        self.deserializer.deserialize_enum(name, variants, visitor)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.deserializer.is_human_readable()
+    }
+}
+
+/// Expects the same as the wrapped visitor, in a [Context]
+struct ContextVisitor<V> {
+    visitor: V,
+    context: Context,
+}
+
+/// Forwards the `visit_*` methods taking a single value
+macro_rules! forward_visit {
+    ($($method:ident($ty:ty))*) => {$(
+        fn $method<E: Error>(self, value: $ty) -> Result<V::Value, E> {
+            self.visitor.$method(value)
+        }
+    )*};
+}
+
+impl<'de, V: Visitor<'de>> Visitor<'de> for ContextVisitor<V> {
+    type Value = V::Value;
+
+    forward_visit! {
+        visit_bool(bool)
+        visit_i8(i8) visit_i16(i16) visit_i32(i32) visit_i64(i64) visit_i128(i128)
+        visit_u8(u8) visit_u16(u16) visit_u32(u32) visit_u64(u64) visit_u128(u128)
+        visit_f32(f32) visit_f64(f64) visit_char(char)
+        visit_str(&str) visit_borrowed_str(&'de str) visit_string(String)
+        visit_bytes(&[u8]) visit_borrowed_bytes(&'de [u8]) visit_byte_buf(Vec<u8>)
+    }
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        self.visitor.expecting(formatter)?;
+        write!(formatter, " as {}", self.context)
+    }
+
+    fn visit_none<E: Error>(self) -> Result<V::Value, E> {
+        self.visitor.visit_none()
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<V::Value, D::Error> {
+        self.visitor.visit_some(ContextDeserializer {
+            deserializer,
+            context: self.context,
+        })
+    }
+
+    fn visit_unit<E: Error>(self) -> Result<V::Value, E> {
+        self.visitor.visit_unit()
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<V::Value, D::Error> {
+        self.visitor.visit_newtype_struct(ContextDeserializer {
+            deserializer,
+            context: self.context,
+        })
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, access: A) -> Result<V::Value, A::Error> {
+        self.visitor.visit_seq(access)
+    }
+
+    fn visit_map<M: MapAccess<'de>>(self, access: M) -> Result<V::Value, M::Error> {
+        self.visitor.visit_map(access)
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(self, access: A) -> Result<V::Value, A::Error> {
+        self.visitor.visit_enum(access)
+    }
+}
@@ -0,0 +1,230 @@
+//! A map from each left value to the sequence of its right values
+//!
+//! ```
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Groups {
+//!     #[serde(with = "bimultimap::serde::grouped")]
+//!     members: bimultimap::BiMultiMap<String, u8>,
+//! }
+//!
+//! let groups = Groups {
+//!     members: bimultimap::BiMultiMap::from_iter([(String::from("admin"), 0)]),
+//! };
+//!
+//! assert_eq!(serde_json::to_string(&groups).unwrap(), r#"{"members":{"admin":[0]}}"#);
+//! ```
+//!
+//! Deserialization fails if a left value is repeated, or mapped to an empty sequence or to the
+//! same right value twice.
+
+use {
+    super::{
+        Marker,
+        context::{Context, GroupInContext, InContext, Role},
+    },
+    crate::{BiMultiMap, InnerMap, InnerSet, Key, MapHasher, Rc},
+    serde::{
+        Deserialize,
+        Deserializer,
+        Serialize,
+        Serializer,
+        de::{DeserializeSeed, Error, MapAccess, Visitor},
+    },
+    std::{fmt, marker::PhantomData, ops::Deref},
+};
+
+pub fn serialize<L, R, S, Ser>(
+    map: &BiMultiMap<L, R, S>,
+    serializer: Ser,
+) -> Result<Ser::Ok, Ser::Error>
+where
+    L: Key + Serialize,
+    R: Key + Serialize,
+    S: MapHasher,
+    Ser: Serializer,
+{
+    Side::<L, R, S>(&map.left_map_rc).serialize(serializer)
+}
+
+pub fn deserialize<'de, L, R, S, D>(deserializer: D) -> Result<BiMultiMap<L, R, S>, D::Error>
+where
+    L: Key + Deserialize<'de>,
+    R: Key + Deserialize<'de>,
+    S: MapHasher,
+    D: Deserializer<'de>,
+{
+    Grouped::new(Role::Left).deserialize(deserializer)
+}
+
+/// One side of a [BiMultiMap], serialized as a map from each key to the sequence of its partners
+pub(crate) struct Side<'a, K: 'a, V: 'a, S>(pub(crate) &'a InnerMap<Rc<K>, InnerSet<Rc<V>, S>, S>);
+
+impl<'a, K: Key, V: Key, S: MapHasher> Side<'a, K, V, S> {
+    /// Each key with its partners
+    pub(crate) fn groups(&self) -> impl Iterator<Item = (&'a K, Values<'a, V, S>)> {
+        self.0
+            .iter()
+            .map(|(key, values)| (key.deref(), Values(values)))
+    }
+}
+
+impl<K: Key + Serialize, V: Key + Serialize, S: MapHasher> Serialize for Side<'_, K, V, S> {
+    fn serialize<Ser: Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        serializer.collect_map(self.groups())
+    }
+}
+
+/// Same as [Side], with the keys and their partners sorted
+pub(crate) struct SortedSide<'a, K: 'a, V: 'a, S>(
+    pub(crate) &'a InnerMap<Rc<K>, InnerSet<Rc<V>, S>, S>,
+);
+
+impl<'a, K: Key + Ord, V: Key, S: MapHasher> SortedSide<'a, K, V, S> {
+    /// Each key with its partners, in ascending order of keys
+    pub(crate) fn groups(&self) -> impl Iterator<Item = (&'a K, SortedValues<'a, V, S>)> {
+        let mut groups = self
+            .0
+            .iter()
+            .map(|(key, values)| (key.deref(), SortedValues(values)))
+            .collect::<Vec<_>>();
+
+        groups.sort_unstable_by_key(|(key, _)| *key);
+        groups.into_iter()
+    }
+}
+
+impl<K, V, S> Serialize for SortedSide<'_, K, V, S>
+where
+    K: Key + Ord + Serialize,
+    V: Key + Ord + Serialize,
+    S: MapHasher,
+{
+    fn serialize<Ser: Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        serializer.collect_map(self.groups())
+    }
+}
+
+/// The partners of a key, serialized as a sequence
+pub(crate) struct Values<'a, V: 'a, S>(pub(crate) &'a InnerSet<Rc<V>, S>);
+
+impl<V: Key + Serialize, S: MapHasher> Serialize for Values<'_, V, S> {
+    fn serialize<Ser: Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        serializer.collect_seq(self.0.iter().map(Deref::deref))
+    }
+}
+
+/// Same as [Values], sorted
+pub(crate) struct SortedValues<'a, V: 'a, S>(pub(crate) &'a InnerSet<Rc<V>, S>);
+
+impl<V: Key + Ord + Serialize, S: MapHasher> Serialize for SortedValues<'_, V, S> {
+    fn serialize<Ser: Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        let mut values = self.0.iter().map(Deref::deref).collect::<Vec<_>>();
+
+        values.sort_unstable();
+        serializer.collect_seq(values)
+    }
+}
+
+/// Maps `key` to all of `values`, failing if the group at `index` couldn't have been serialized
+///
+/// `keys` is the side of `key`, for a map grouped by its right values.
+pub(crate) fn insert_group<K: Key, V: Key, S: MapHasher, E: Error>(
+    map: &mut BiMultiMap<K, V, S>,
+    keys: Role,
+    index: usize,
+    key: K,
+    values: Vec<V>,
+) -> Result<(), E> {
+    if map.contains_left(&key) {
+        return Err(E::custom(format_args!(
+            "the {keys} value of the group at index {index} appears in an earlier group"
+        )));
+    }
+
+    let len = values.len();
+
+    if len == 0 {
+        return Err(E::custom(format_args!(
+            "the {keys} value of the group at index {index} is mapped to an empty sequence of {} \
+             values",
+            keys.other()
+        )));
+    }
+
+    if map.extend_left(key, values) != len {
+        return Err(E::custom(format_args!(
+            "the {keys} value of the group at index {index} is mapped to the same {} value twice",
+            keys.other()
+        )));
+    }
+
+    Ok(())
+}
+
+/// Deserializes a [BiMultiMap] like [deserialize], to nest it in other representations
+///
+/// `keys` is the side of the keys of the map in the errors, for a map grouped by its right
+/// values.
+pub(crate) struct Grouped<K: Key, V: Key, S: MapHasher> {
+    keys:   Role,
+    marker: Marker<K, V, S>,
+}
+
+impl<K: Key, V: Key, S: MapHasher> Grouped<K, V, S> {
+    pub(crate) fn new(keys: Role) -> Self {
+        Grouped {
+            keys,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'de, K, V, S> DeserializeSeed<'de> for Grouped<K, V, S>
+where
+    K: Key + Deserialize<'de>,
+    V: Key + Deserialize<'de>,
+    S: MapHasher,
+{
+    type Value = BiMultiMap<K, V, S>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de, K, V, S> Visitor<'de> for Grouped<K, V, S>
+where
+    K: Key + Deserialize<'de>,
+    V: Key + Deserialize<'de>,
+    S: MapHasher,
+{
+    type Value = BiMultiMap<K, V, S>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "a map from each {} value to a non-empty sequence of {} values",
+            self.keys,
+            self.keys.other()
+        )
+    }
+
+    fn visit_map<M: MapAccess<'de>>(self, mut access: M) -> Result<Self::Value, M::Error> {
+        let mut map = BiMultiMap::default();
+        let mut index = 0;
+
+        while let Some(key) =
+            access.next_key_seed(InContext::new(Context::group(self.keys, index)))?
+        {
+            let values = access.next_value_seed(GroupInContext::new(Context::group(
+                self.keys.other(),
+                index,
+            )))?;
+
+            insert_group(&mut map, self.keys, index, key, values)?;
+            index += 1;
+        }
+
+        Ok(map)
+    }
+}
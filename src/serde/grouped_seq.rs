@@ -0,0 +1,92 @@
+//! A sequence of `[left, [right...]]` pairs, one per left value
+//!
+//! It's the same as [grouped](super::grouped), for left values that can't be map keys, like
+//! tuples or structs in JSON.
+//!
+//! ```
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Edges {
+//!     #[serde(with = "bimultimap::serde::grouped_seq")]
+//!     targets: bimultimap::BiMultiMap<(u32, u32), String>,
+//! }
+//!
+//! let edges = Edges {
+//!     targets: bimultimap::BiMultiMap::from_iter([((0, 1), String::from("a"))]),
+//! };
+//!
+//! assert_eq!(serde_json::to_string(&edges).unwrap(), r#"{"targets":[[[0,1],["a"]]]}"#);
+//! ```
+//!
+//! Deserialization fails if a left value is repeated, or mapped to an empty sequence or to the
+//! same right value twice.
+
+use {
+    super::{
+        Marker,
+        context::{Pair, Role},
+        grouped::{Side, insert_group},
+    },
+    crate::{BiMultiMap, Key, MapHasher},
+    serde::{
+        Deserialize,
+        Deserializer,
+        Serialize,
+        Serializer,
+        de::{SeqAccess, Visitor},
+    },
+    std::{fmt, marker::PhantomData},
+};
+
+pub fn serialize<L, R, S, Ser>(
+    map: &BiMultiMap<L, R, S>,
+    serializer: Ser,
+) -> Result<Ser::Ok, Ser::Error>
+where
+    L: Key + Serialize,
+    R: Key + Serialize,
+    S: MapHasher,
+    Ser: Serializer,
+{
+    serializer.collect_seq(Side::<L, R, S>(&map.left_map_rc).groups())
+}
+
+pub fn deserialize<'de, L, R, S, D>(deserializer: D) -> Result<BiMultiMap<L, R, S>, D::Error>
+where
+    L: Key + Deserialize<'de>,
+    R: Key + Deserialize<'de>,
+    S: MapHasher,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_seq(GroupedSeqVisitor {
+        marker: PhantomData,
+    })
+}
+
+struct GroupedSeqVisitor<L: Key, R: Key, S: MapHasher> {
+    marker: Marker<L, R, S>,
+}
+
+impl<'de, L, R, S> Visitor<'de> for GroupedSeqVisitor<L, R, S>
+where
+    L: Key + Deserialize<'de>,
+    R: Key + Deserialize<'de>,
+    S: MapHasher,
+{
+    type Value = BiMultiMap<L, R, S>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a sequence of [left, [right...]] pairs")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut access: A) -> Result<Self::Value, A::Error> {
+        let mut map = BiMultiMap::default();
+        let mut index = 0;
+
+        while let Some((left, right_values)) = access.next_element_seed(Pair::group(index))? {
+            insert_group(&mut map, Role::Left, index, left, right_values)?;
+            index += 1;
+        }
+
+        Ok(map)
+    }
+}
@@ -0,0 +1,90 @@
+//! A sequence of `[left, right]` pairs, one per mapping
+//!
+//! ```
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Groups {
+//!     #[serde(with = "bimultimap::serde::pairs")]
+//!     members: bimultimap::BiMultiMap<String, u8>,
+//! }
+//!
+//! let groups = Groups {
+//!     members: bimultimap::BiMultiMap::from_iter([(String::from("admin"), 0)]),
+//! };
+//!
+//! assert_eq!(serde_json::to_string(&groups).unwrap(), r#"{"members":[["admin",0]]}"#);
+//! ```
+//!
+//! Deserialization fails if a mapping appears twice.
+
+use {
+    super::{Marker, context::Pair, with_size_hint},
+    crate::{BiMultiMap, Key, MapHasher},
+    serde::{
+        Deserialize,
+        Deserializer,
+        Serialize,
+        Serializer,
+        de::{Error, SeqAccess, Visitor},
+    },
+    std::{fmt, marker::PhantomData},
+};
+
+pub fn serialize<L, R, S, Ser>(
+    map: &BiMultiMap<L, R, S>,
+    serializer: Ser,
+) -> Result<Ser::Ok, Ser::Error>
+where
+    L: Key + Serialize,
+    R: Key + Serialize,
+    S: MapHasher,
+    Ser: Serializer,
+{
+    serializer.collect_seq(map.iter_ref())
+}
+
+pub fn deserialize<'de, L, R, S, D>(deserializer: D) -> Result<BiMultiMap<L, R, S>, D::Error>
+where
+    L: Key + Deserialize<'de>,
+    R: Key + Deserialize<'de>,
+    S: MapHasher,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_seq(PairsVisitor {
+        marker: PhantomData,
+    })
+}
+
+struct PairsVisitor<L: Key, R: Key, S: MapHasher> {
+    marker: Marker<L, R, S>,
+}
+
+impl<'de, L, R, S> Visitor<'de> for PairsVisitor<L, R, S>
+where
+    L: Key + Deserialize<'de>,
+    R: Key + Deserialize<'de>,
+    S: MapHasher,
+{
+    type Value = BiMultiMap<L, R, S>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a sequence of [left, right] pairs")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut access: A) -> Result<Self::Value, A::Error> {
+        let mut map = with_size_hint(access.size_hint());
+        let mut index = 0;
+
+        while let Some((left, right)) = access.next_element_seed(Pair::mapping(index))? {
+            if !map.insert(left, right) {
+                return Err(A::Error::custom(format_args!(
+                    "the left and right values of the mapping at index {index} are already \
+                     mapped to each other"
+                )));
+            }
+
+            index += 1;
+        }
+
+        Ok(map)
+    }
+}
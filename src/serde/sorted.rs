@@ -0,0 +1,158 @@
+//! The same representations, with the values sorted
+//!
+//! Equal maps always serialize to the same output with these modules, whatever their insertion
+//! order or their hasher, which makes the output fit for hashing or for comparing with a
+//! reference file. Serializing sorts the values first, so it's slower and allocates, and requires
+//! both sides to be [Ord]. Deserialization is the same as with the unsorted representation.
+//!
+//! This module itself is the sorted version of the default representation.
+//!
+//! ```
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Groups {
+//!     #[serde(with = "bimultimap::serde::sorted::grouped")]
+//!     members: bimultimap::BiMultiMap<String, u8>,
+//! }
+//!
+//! let groups = Groups {
+//!     members: bimultimap::BiMultiMap::from_iter([
+//!         (String::from("user"), 1),
+//!         (String::from("admin"), 1),
+//!         (String::from("admin"), 0),
+//!     ]),
+//! };
+//!
+//! assert_eq!(
+//!     serde_json::to_string(&groups).unwrap(),
+//!     r#"{"members":{"admin":[0,1],"user":[1]}}"#
+//! );
+//! ```
+
+use {
+    crate::{BiMultiMap, Key, MapHasher},
+    serde::{Deserialize, Deserializer, Serialize, Serializer},
+};
+
+pub fn serialize<L, R, S, Ser>(
+    map: &BiMultiMap<L, R, S>,
+    serializer: Ser,
+) -> Result<Ser::Ok, Ser::Error>
+where
+    L: Key + Ord + Serialize,
+    R: Key + Ord + Serialize,
+    S: MapHasher,
+    Ser: Serializer,
+{
+    serializer.collect_map(map.iter_sorted())
+}
+
+pub fn deserialize<'de, L, R, S, D>(deserializer: D) -> Result<BiMultiMap<L, R, S>, D::Error>
+where
+    L: Key + Deserialize<'de>,
+    R: Key + Deserialize<'de>,
+    S: MapHasher,
+    D: Deserializer<'de>,
+{
+    BiMultiMap::deserialize(deserializer)
+}
+
+/// Same as [grouped](super::super::grouped), sorted by left value then by right value
+pub mod grouped {
+    use {
+        crate::{BiMultiMap, Key, MapHasher, serde::grouped::SortedSide},
+        serde::{Serialize, Serializer},
+    };
+
+    pub use crate::serde::grouped::deserialize;
+
+    pub fn serialize<L, R, S, Ser>(
+        map: &BiMultiMap<L, R, S>,
+        serializer: Ser,
+    ) -> Result<Ser::Ok, Ser::Error>
+    where
+        L: Key + Ord + Serialize,
+        R: Key + Ord + Serialize,
+        S: MapHasher,
+        Ser: Serializer,
+    {
+        SortedSide::<L, R, S>(&map.left_map_rc).serialize(serializer)
+    }
+}
+
+/// Same as [grouped_seq](super::super::grouped_seq), sorted by left value then by right value
+pub mod grouped_seq {
+    use {
+        crate::{BiMultiMap, Key, MapHasher, serde::grouped::SortedSide},
+        serde::{Serialize, Serializer},
+    };
+
+    pub use crate::serde::grouped_seq::deserialize;
+
+    pub fn serialize<L, R, S, Ser>(
+        map: &BiMultiMap<L, R, S>,
+        serializer: Ser,
+    ) -> Result<Ser::Ok, Ser::Error>
+    where
+        L: Key + Ord + Serialize,
+        R: Key + Ord + Serialize,
+        S: MapHasher,
+        Ser: Serializer,
+    {
+        serializer.collect_seq(SortedSide::<L, R, S>(&map.left_map_rc).groups())
+    }
+}
+
+/// Same as [pairs](super::super::pairs), sorted by left value then by right value
+pub mod pairs {
+    use {
+        crate::{BiMultiMap, Key, MapHasher},
+        serde::{Serialize, Serializer},
+    };
+
+    pub use crate::serde::pairs::deserialize;
+
+    pub fn serialize<L, R, S, Ser>(
+        map: &BiMultiMap<L, R, S>,
+        serializer: Ser,
+    ) -> Result<Ser::Ok, Ser::Error>
+    where
+        L: Key + Ord + Serialize,
+        R: Key + Ord + Serialize,
+        S: MapHasher,
+        Ser: Serializer,
+    {
+        serializer.collect_seq(map.iter_sorted())
+    }
+}
+
+/// Same as [two_maps](super::super::two_maps), with each side sorted
+pub mod two_maps {
+    use {
+        crate::{
+            BiMultiMap,
+            Key,
+            MapHasher,
+            serde::{grouped::SortedSide, two_maps::serialize_sides},
+        },
+        serde::{Serialize, Serializer},
+    };
+
+    pub use crate::serde::two_maps::deserialize;
+
+    pub fn serialize<L, R, S, Ser>(
+        map: &BiMultiMap<L, R, S>,
+        serializer: Ser,
+    ) -> Result<Ser::Ok, Ser::Error>
+    where
+        L: Key + Ord + Serialize,
+        R: Key + Ord + Serialize,
+        S: MapHasher,
+        Ser: Serializer,
+    {
+        serialize_sides(
+            &SortedSide::<L, R, S>(&map.left_map_rc),
+            &SortedSide::<R, L, S>(&map.right_map_rc),
+            serializer,
+        )
+    }
+}
@@ -0,0 +1,48 @@
+//! The default representation, failing to deserialize a mapping that appears twice
+//!
+//! ```
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Groups {
+//!     #[serde(with = "bimultimap::serde::strict")]
+//!     members: bimultimap::BiMultiMap<String, u8>,
+//! }
+//!
+//! let error = serde_json::from_str::<Groups>(r#"{"members":{"admin":0,"admin":0}}"#)
+//!     .err()
+//!     .unwrap();
+//!
+//! assert_eq!(
+//!     error.to_string(),
+//!     "the left and right values of the mapping at index 1 are already mapped to each other at \
+//!      line 1 column 32"
+//! );
+//! ```
+
+use {
+    super::deserialize_flat,
+    crate::{BiMultiMap, Key, MapHasher},
+    serde::{Deserialize, Deserializer, Serialize, Serializer},
+};
+
+pub fn serialize<L, R, S, Ser>(
+    map: &BiMultiMap<L, R, S>,
+    serializer: Ser,
+) -> Result<Ser::Ok, Ser::Error>
+where
+    L: Key + Serialize,
+    R: Key + Serialize,
+    S: MapHasher,
+    Ser: Serializer,
+{
+    map.serialize(serializer)
+}
+
+pub fn deserialize<'de, L, R, S, D>(deserializer: D) -> Result<BiMultiMap<L, R, S>, D::Error>
+where
+    L: Key + Deserialize<'de>,
+    R: Key + Deserialize<'de>,
+    S: MapHasher,
+    D: Deserializer<'de>,
+{
+    deserialize_flat(deserializer, true)
+}
@@ -0,0 +1,188 @@
+//! A struct with a `left` and a `right` field, each side grouped like with
+//! [grouped](super::grouped)
+//!
+//! ```
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Groups {
+//!     #[serde(with = "bimultimap::serde::two_maps")]
+//!     members: bimultimap::BiMultiMap<String, String>,
+//! }
+//!
+//! let groups = Groups {
+//!     members: bimultimap::BiMultiMap::from_iter([("admin".into(), "alice".into())]),
+//! };
+//!
+//! assert_eq!(
+//!     serde_json::to_string(&groups).unwrap(),
+//!     r#"{"members":{"left":{"admin":["alice"]},"right":{"alice":["admin"]}}}"#
+//! );
+//! ```
+//!
+//! The redundancy makes each side readable on its own. Deserialization fails if either side is
+//! malformed, or if the sides don't mirror each other.
+
+use {
+    super::{
+        Marker,
+        context::Role,
+        grouped::{Grouped, Side},
+    },
+    crate::{BiMultiMap, Key, MapHasher},
+    serde::{
+        Deserialize,
+        Deserializer,
+        Serialize,
+        Serializer,
+        de::{Error, MapAccess, SeqAccess, Visitor},
+        ser::SerializeStruct,
+    },
+    std::{fmt, marker::PhantomData},
+};
+
+const FIELDS: &[&str] = &["left", "right"];
+
+pub fn serialize<L, R, S, Ser>(
+    map: &BiMultiMap<L, R, S>,
+    serializer: Ser,
+) -> Result<Ser::Ok, Ser::Error>
+where
+    L: Key + Serialize,
+    R: Key + Serialize,
+    S: MapHasher,
+    Ser: Serializer,
+{
+    serialize_sides(
+        &Side::<L, R, S>(&map.left_map_rc),
+        &Side::<R, L, S>(&map.right_map_rc),
+        serializer,
+    )
+}
+
+/// Serializes the struct, whatever the order of each side
+pub(crate) fn serialize_sides<Ser: Serializer>(
+    left: &impl Serialize,
+    right: &impl Serialize,
+    serializer: Ser,
+) -> Result<Ser::Ok, Ser::Error> {
+    let mut state = serializer.serialize_struct("BiMultiMap", FIELDS.len())?;
+    state.serialize_field("left", left)?;
+    state.serialize_field("right", right)?;
+    state.end()
+}
+
+pub fn deserialize<'de, L, R, S, D>(deserializer: D) -> Result<BiMultiMap<L, R, S>, D::Error>
+where
+    L: Key + Deserialize<'de>,
+    R: Key + Deserialize<'de>,
+    S: MapHasher,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_struct(
+        "BiMultiMap",
+        FIELDS,
+        TwoMapsVisitor {
+            marker: PhantomData,
+        },
+    )
+}
+
+/// Checks that `right` is the mirror of `left`, which is then the deserialized map
+fn mirrored<L: Key, R: Key, S: MapHasher, E: Error>(
+    left: BiMultiMap<L, R, S>,
+    right: BiMultiMap<R, L, S>,
+) -> Result<BiMultiMap<L, R, S>, E> {
+    if left == right.into_inverse() {
+        Ok(left)
+    } else {
+        Err(E::custom("the left and right maps don't mirror each other"))
+    }
+}
+
+enum Field {
+    Left,
+    Right,
+}
+
+impl<'de> Deserialize<'de> for Field {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_identifier(FieldVisitor)
+    }
+}
+
+struct FieldVisitor;
+
+impl Visitor<'_> for FieldVisitor {
+    type Value = Field;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("`left` or `right`")
+    }
+
+    fn visit_u64<E: Error>(self, value: u64) -> Result<Self::Value, E> {
+        match value {
+            0 => Ok(Field::Left),
+            1 => Ok(Field::Right),
+            _ => {
+                Err(E::invalid_value(
+                    serde::de::Unexpected::Unsigned(value),
+                    &self,
+                ))
+            },
+        }
+    }
+
+    fn visit_str<E: Error>(self, value: &str) -> Result<Self::Value, E> {
+        match value {
+            "left" => Ok(Field::Left),
+            "right" => Ok(Field::Right),
+            _ => Err(E::unknown_field(value, FIELDS)),
+        }
+    }
+}
+
+struct TwoMapsVisitor<L: Key, R: Key, S: MapHasher> {
+    marker: Marker<L, R, S>,
+}
+
+impl<'de, L, R, S> Visitor<'de> for TwoMapsVisitor<L, R, S>
+where
+    L: Key + Deserialize<'de>,
+    R: Key + Deserialize<'de>,
+    S: MapHasher,
+{
+    type Value = BiMultiMap<L, R, S>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a struct with a `left` and a `right` map")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut access: A) -> Result<Self::Value, A::Error> {
+        let left = access
+            .next_element_seed(Grouped::new(Role::Left))?
+            .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+        let right = access
+            .next_element_seed(Grouped::new(Role::Right))?
+            .ok_or_else(|| A::Error::invalid_length(1, &self))?;
+
+        mirrored(left, right)
+    }
+
+    fn visit_map<M: MapAccess<'de>>(self, mut access: M) -> Result<Self::Value, M::Error> {
+        let mut left = None;
+        let mut right = None;
+
+        while let Some(field) = access.next_key()? {
+            match field {
+                Field::Left if left.is_some() => return Err(M::Error::duplicate_field("left")),
+                Field::Right if right.is_some() => return Err(M::Error::duplicate_field("right")),
+                Field::Left => left = Some(access.next_value_seed(Grouped::new(Role::Left))?),
+                Field::Right => right = Some(access.next_value_seed(Grouped::new(Role::Right))?),
+            }
+        }
+
+        mirrored(
+            left.ok_or_else(|| M::Error::missing_field("left"))?,
+            right.ok_or_else(|| M::Error::missing_field("right"))?,
+        )
+    }
+}
@@ -0,0 +1,387 @@
+use {
+    crate::{
+        BiMultiMap,
+        DefaultHashBuilder,
+        InnerMap,
+        InnerSet,
+        IterEdges,
+        Key,
+        Map,
+        MapHasher,
+        Rc,
+        intern,
+        lookup::lookup,
+    },
+    std::{
+        borrow::Borrow,
+        fmt::{self, Debug, Formatter},
+    },
+};
+
+/// A [BiMultiMap] where each (L, R) mapping carries a value of type `V`
+///
+/// The values are only reachable through the mappings, so they can't get out of sync with them.
+/// They are stored with the right keys of each left key, the right side only indexing the left
+/// keys of each right key, so a mapping takes the same room as in a [BiMultiMap] plus its value.
+pub struct BiMultiMapWithValues<L: Key, R: Key, V, S: MapHasher = DefaultHashBuilder> {
+    left_map_rc:  InnerMap<Rc<L>, InnerMap<Rc<R>, V, S>, S>,
+    right_map_rc: InnerMap<Rc<R>, InnerSet<Rc<L>, S>, S>,
+    len:          usize,
+}
+
+impl<L: Key + Debug, R: Key + Debug, V: Debug, S: MapHasher> Debug
+    for BiMultiMapWithValues<L, R, V, S>
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BiMultiMapWithValues")
+            .field("left_map_rc", &self.left_map_rc)
+            .field("right_map_rc", &self.right_map_rc)
+            .field("len", &self.len)
+            .finish()
+    }
+}
+
+impl<L: Key, R: Key, V, S: MapHasher> Default for BiMultiMapWithValues<L, R, V, S> {
+    fn default() -> Self {
+        BiMultiMapWithValues {
+            left_map_rc:  InnerMap::<_, _, S>::default(),
+            right_map_rc: InnerMap::<_, _, S>::default(),
+            len:          0usize,
+        }
+    }
+}
+
+impl<L: Key, R: Key, V: PartialEq, S: MapHasher> PartialEq for BiMultiMapWithValues<L, R, V, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len &&
+            self.left_map_rc == other.left_map_rc &&
+            self.right_map_rc == other.right_map_rc
+    }
+}
+
+impl<L: Key, R: Key, V: Eq, S: MapHasher> Eq for BiMultiMapWithValues<L, R, V, S> {}
+
+impl<L: Key, R: Key, V: Clone, S: MapHasher> Clone for BiMultiMapWithValues<L, R, V, S> {
+    fn clone(&self) -> Self {
+        BiMultiMapWithValues {
+            left_map_rc:  self.left_map_rc.clone(),
+            right_map_rc: self.right_map_rc.clone(),
+            len:          self.len,
+        }
+    }
+}
+
+impl<L: Key, R: Key, V> FromIterator<(L, R, V)> for BiMultiMapWithValues<L, R, V> {
+    fn from_iter<T: IntoIterator<Item = (L, R, V)>>(iter: T) -> Self {
+        let mut map = BiMultiMapWithValues::new();
+        iter.into_iter().for_each(|(left, right, value)| {
+            map.insert(left, right, value);
+        });
+        map
+    }
+}
+
+/// Only for the default hasher, like the `From` implementation of [BiMultiMap]
+impl<L: Key, R: Key, V, const N: usize> From<[(L, R, V); N]> for BiMultiMapWithValues<L, R, V> {
+    fn from(mappings: [(L, R, V); N]) -> Self {
+        BiMultiMapWithValues::from_iter(mappings)
+    }
+}
+
+impl<L: Key, R: Key, V> BiMultiMapWithValues<L, R, V> {
+    pub fn new() -> Self {
+        BiMultiMapWithValues {
+            left_map_rc:  Map::new(),
+            right_map_rc: Map::new(),
+            len:          0usize,
+        }
+    }
+}
+
+impl<L: Key, R: Key, V, S: MapHasher> BiMultiMapWithValues<L, R, V, S> {
+    /// Creates an empty [BiMultiMapWithValues] hashing its keys with `hasher`
+    #[cfg(any(feature = "hashmap", feature = "hashbrown"))]
+    pub fn with_hasher(hasher: S) -> Self {
+        BiMultiMapWithValues {
+            left_map_rc:  InnerMap::with_hasher(hasher.clone()),
+            right_map_rc: InnerMap::with_hasher(hasher),
+            len:          0usize,
+        }
+    }
+
+    /// Drops the values, keeping only the mappings
+    pub fn into_map(self) -> BiMultiMap<L, R, S> {
+        BiMultiMap {
+            left_map_rc:  self
+                .left_map_rc
+                .into_iter()
+                .map(|(left, values)| (left, values.into_keys().collect()))
+                .collect(),
+            right_map_rc: self.right_map_rc,
+            len:          self.len,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Same as [BiMultiMap::contains]
+    pub fn contains<QL, QR>(&self, left: &QL, right: &QR) -> bool
+    where
+        L: Borrow<QL>,
+        R: Borrow<QR>,
+        QL: Key + ?Sized,
+        QR: Key + ?Sized,
+    {
+        self.get_edge(left, right).is_some()
+    }
+
+    /// Same as [BiMultiMap::contains_left]
+    pub fn contains_left<Q>(&self, left: &Q) -> bool
+    where
+        L: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        self.left_map_rc.contains_key(lookup(&left))
+    }
+
+    /// Same as [BiMultiMap::contains_right]
+    pub fn contains_right<Q>(&self, right: &Q) -> bool
+    where
+        R: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        self.right_map_rc.contains_key(lookup(&right))
+    }
+
+    /// Same as [BiMultiMap::left_degree]
+    pub fn left_degree<Q>(&self, left: &Q) -> usize
+    where
+        L: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        self.left_map_rc
+            .get(lookup(&left))
+            .map_or(0, |values| values.len())
+    }
+
+    /// Same as [BiMultiMap::right_degree]
+    pub fn right_degree<Q>(&self, right: &Q) -> usize
+    where
+        R: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        self.right_map_rc
+            .get(lookup(&right))
+            .map_or(0, |left_set| left_set.len())
+    }
+
+    /// Same as [BiMultiMap::left_len]
+    pub fn left_len(&self) -> usize {
+        self.left_map_rc.len()
+    }
+
+    /// Same as [BiMultiMap::right_len]
+    pub fn right_len(&self) -> usize {
+        self.right_map_rc.len()
+    }
+
+    /// Same as [BiMultiMap::check_invariants], the right map indexing the keys of the values of
+    /// the left map.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of these invariants doesn't hold.
+    pub fn check_invariants(&self) {
+        let mut left_len = 0usize;
+
+        for (left, values) in self.left_map_rc.iter() {
+            assert!(!values.is_empty(), "a left key has an empty map of values");
+
+            for right in values.keys() {
+                assert!(
+                    self.right_map_rc
+                        .get(right)
+                        .is_some_and(|lefts| lefts.contains(left)),
+                    "a mapping of the left map is missing from the right map"
+                );
+            }
+
+            left_len += values.len();
+        }
+
+        let mut right_len = 0usize;
+
+        for (right, lefts) in self.right_map_rc.iter() {
+            assert!(!lefts.is_empty(), "a right key is mapped to an empty set");
+
+            for left in lefts {
+                assert!(
+                    self.left_map_rc
+                        .get(left)
+                        .is_some_and(|values| values.contains_key(right)),
+                    "a mapping of the right map is missing from the left map"
+                );
+            }
+
+            right_len += lefts.len();
+        }
+
+        assert_eq!(
+            left_len, right_len,
+            "left and right maps have a different length"
+        );
+        assert_eq!(
+            self.len, left_len,
+            "`len` doesn't match the number of mappings"
+        );
+    }
+
+    /// Inserts a (L, R) mapping carrying `value`
+    ///
+    /// Returns the previous value of the mapping if it already existed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut map = bimultimap::BiMultiMapWithValues::new();
+    ///
+    /// assert_eq!(map.insert("serde", "serde_derive", "=1.0.219"), None);
+    /// assert_eq!(map.insert("serde", "serde_derive", "1.0"), Some("=1.0.219"));
+    /// assert_eq!(map.get_edge("serde", "serde_derive"), Some(&"1.0"));
+    /// ```
+    pub fn insert(&mut self, left: L, right: R, value: V) -> Option<V> {
+        let left_rc = intern(&self.left_map_rc, left);
+        let right_rc = intern(&self.right_map_rc, right);
+
+        let previous = self
+            .left_map_rc
+            .entry(left_rc.clone())
+            .or_default()
+            .insert(right_rc.clone(), value);
+
+        if previous.is_none() {
+            self.right_map_rc
+                .entry(right_rc)
+                .or_default()
+                .insert(left_rc);
+            self.len += 1;
+        }
+
+        previous
+    }
+
+    /// The value carried by the (L, R) mapping
+    pub fn get_edge<QL, QR>(&self, left: &QL, right: &QR) -> Option<&V>
+    where
+        L: Borrow<QL>,
+        R: Borrow<QR>,
+        QL: Key + ?Sized,
+        QR: Key + ?Sized,
+    {
+        self.left_map_rc.get(lookup(&left))?.get(lookup(&right))
+    }
+
+    /// A mutable reference to the value carried by the (L, R) mapping
+    pub fn get_edge_mut<QL, QR>(&mut self, left: &QL, right: &QR) -> Option<&mut V>
+    where
+        L: Borrow<QL>,
+        R: Borrow<QR>,
+        QL: Key + ?Sized,
+        QR: Key + ?Sized,
+    {
+        self.left_map_rc
+            .get_mut(lookup(&left))?
+            .get_mut(lookup(&right))
+    }
+
+    /// Iterates over all the mappings and their values
+    pub fn iter_edges(&self) -> IterEdges<'_, L, R, V, S> {
+        IterEdges::new(&self.left_map_rc, self.len)
+    }
+
+    /// Removes the (L, R) mapping, returning its value
+    pub fn remove<QL, QR>(&mut self, left: &QL, right: &QR) -> Option<V>
+    where
+        L: Borrow<QL>,
+        R: Borrow<QR>,
+        QL: Key + ?Sized,
+        QR: Key + ?Sized,
+    {
+        let values = self.left_map_rc.get_mut(lookup(&left))?;
+        let value = values.remove(lookup(&right))?;
+
+        if values.is_empty() {
+            self.left_map_rc.remove(lookup(&left));
+        }
+
+        let should_remove_right = self.right_map_rc.get_mut(lookup(&right)).map(|left_set| {
+            left_set.remove(lookup(&left));
+            left_set.is_empty()
+        });
+
+        if should_remove_right == Some(true) {
+            self.right_map_rc.remove(lookup(&right));
+        }
+
+        self.len -= 1;
+
+        Some(value)
+    }
+
+    /// Removes a left key and all its mappings, returning the values of its mappings
+    pub fn remove_left<Q>(&mut self, left: &Q) -> Option<InnerMap<Rc<R>, V, S>>
+    where
+        L: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        let values = self.left_map_rc.remove(lookup(&left))?;
+
+        for right in values.keys() {
+            let should_remove_right = self.right_map_rc.get_mut(right).map(|left_set| {
+                left_set.remove(lookup(&left));
+                left_set.is_empty()
+            });
+
+            if should_remove_right == Some(true) {
+                self.right_map_rc.remove(right);
+            }
+        }
+
+        self.len -= values.len();
+
+        Some(values)
+    }
+
+    /// Removes a right key and all its mappings, returning the values of its mappings
+    pub fn remove_right<Q>(&mut self, right: &Q) -> Option<InnerMap<Rc<L>, V, S>>
+    where
+        R: Borrow<Q>,
+        Q: Key + ?Sized,
+    {
+        let left_set = self.right_map_rc.remove(lookup(&right))?;
+
+        self.len -= left_set.len();
+
+        Some(
+            left_set
+                .into_iter()
+                .filter_map(|left| {
+                    let values = self.left_map_rc.get_mut(&left)?;
+                    let value = values.remove(lookup(&right));
+
+                    if values.is_empty() {
+                        self.left_map_rc.remove(&left);
+                    }
+
+                    Some((left, value?))
+                })
+                .collect(),
+        )
+    }
+}
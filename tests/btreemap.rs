@@ -0,0 +1,44 @@
+#![cfg(feature = "btreemap")]
+
+use bimultimap::{BiMultiMap, Rc, Set};
+
+#[test]
+fn sorted_iter() {
+    let map = BiMultiMap::from_iter([(3, 'c'), (1, 'b'), (2, 'a'), (1, 'a')]);
+
+    assert_eq!(
+        map.iter_ref().collect::<Vec<_>>(),
+        vec![
+            (&1, &'a'),
+            (&1, &'b'),
+            (&2, &'a'),
+            (&3, &'c')
+        ]
+    );
+}
+
+#[test]
+fn sorted_keys() {
+    let map = BiMultiMap::from_iter([(3, 'c'), (1, 'b'), (2, 'a'), (1, 'a')]);
+
+    assert_eq!(
+        map.left_values().map(|l| **l).collect::<Vec<_>>(),
+        vec![1, 2, 3]
+    );
+    assert_eq!(
+        map.right_values().map(|r| **r).collect::<Vec<_>>(),
+        vec!['a', 'b', 'c']
+    );
+}
+
+#[test]
+fn sorted_get() {
+    let map = BiMultiMap::from_iter([(3, 'a'), (1, 'b'), (2, 'a'), (1, 'a')]);
+
+    assert_eq!(map.get_left_vec(&1), Some(vec![&'a', &'b']));
+    assert_eq!(map.get_right_vec(&'a'), Some(vec![&1, &2, &3]));
+    assert_eq!(
+        map.get_right(&'a'),
+        Some(&Set::from([Rc::new(1), Rc::new(2), Rc::new(3)]))
+    );
+}
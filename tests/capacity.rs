@@ -0,0 +1,77 @@
+#![cfg(any(feature = "hashmap", feature = "hashbrown"))]
+
+use {
+    bimultimap::{BiMultiMap, BiMultiMapWithValues},
+    std::hash::{BuildHasherDefault, DefaultHasher},
+};
+
+type FixedHasher = BuildHasherDefault<DefaultHasher>;
+
+#[test]
+fn with_capacity() {
+    let mut map = BiMultiMap::with_capacity(100, 10);
+
+    assert!(map.left_capacity() >= 100);
+    assert!(map.right_capacity() >= 10);
+
+    map.insert(0, 'a');
+
+    assert_eq!(map.len(), 1);
+}
+
+#[test]
+fn reserve() {
+    let mut map = BiMultiMap::from_iter([(0, 'a')]);
+
+    map.reserve_left(1000);
+    map.reserve_right(500);
+
+    assert!(map.left_capacity() >= 1001);
+    assert!(map.right_capacity() >= 501);
+    assert_eq!(map.get_left_vec(&0), Some(vec![&'a']));
+}
+
+#[test]
+fn shrink_to_fit() {
+    let mut map = BiMultiMap::with_capacity(1000, 1000);
+
+    map.insert(0, 'a');
+    map.insert(1, 'a');
+    map.shrink_to_fit();
+
+    assert!(map.left_capacity() < 1000);
+    assert!(map.right_capacity() < 1000);
+    assert_eq!(map.len(), 2);
+    map.check_invariants();
+}
+
+#[test]
+fn with_hasher() {
+    let mut map = BiMultiMap::<_, _, FixedHasher>::with_hasher(FixedHasher::default());
+
+    assert!(map.insert("a", 0));
+    assert!(map.insert("a", 1));
+    assert!(map.insert("b", 1));
+    assert!(!map.insert("b", 1));
+
+    assert_eq!(map.len(), 3);
+    assert_eq!(map.left_degree("a"), 2);
+    assert!(map.remove("a", &0));
+    assert_eq!(map.clone(), map);
+    map.check_invariants();
+
+    let drained = map.drain().count();
+
+    assert_eq!(drained, 2);
+    assert!(map.is_empty());
+}
+
+#[test]
+fn values_with_hasher() {
+    let mut map = BiMultiMapWithValues::with_hasher(FixedHasher::default());
+
+    map.insert("serde", "serde_derive", "1.0");
+
+    assert_eq!(map.get_edge("serde", "serde_derive"), Some(&"1.0"));
+    map.check_invariants();
+}
@@ -0,0 +1,103 @@
+use bimultimap::{BiMultiMap, Component, Set};
+
+fn as_sets(component: Component<'_, &'static str, i32>) -> (Set<&'static str>, Set<i32>) {
+    (
+        component.lefts.into_iter().copied().collect(),
+        component.rights.into_iter().copied().collect(),
+    )
+}
+
+#[test]
+fn component_of_left() {
+    let map = BiMultiMap::from_iter([
+        ("alice", 0),
+        ("bob", 0),
+        ("bob", 1),
+        ("carol", 1),
+        ("dave", 2),
+        ("erin", 3),
+        ("erin", 4),
+    ]);
+
+    assert_eq!(
+        as_sets(map.component_of_left("alice").unwrap()),
+        (Set::from(["alice", "bob", "carol"]), Set::from([0, 1]))
+    );
+    assert_eq!(
+        as_sets(map.component_of_left("dave").unwrap()),
+        (Set::from(["dave"]), Set::from([2]))
+    );
+    assert_eq!(map.component_of_left("missing"), None);
+}
+
+#[test]
+fn component_of_right() {
+    let map = BiMultiMap::from_iter([
+        ("alice", 0),
+        ("bob", 0),
+        ("bob", 1),
+        ("carol", 1),
+        ("dave", 2),
+        ("erin", 3),
+        ("erin", 4),
+    ]);
+
+    assert_eq!(
+        as_sets(map.component_of_right(&1).unwrap()),
+        (Set::from(["alice", "bob", "carol"]), Set::from([0, 1]))
+    );
+    assert_eq!(
+        as_sets(map.component_of_right(&4).unwrap()),
+        (Set::from(["erin"]), Set::from([3, 4]))
+    );
+    assert_eq!(map.component_of_right(&5), None);
+}
+
+#[test]
+fn no_duplicates() {
+    let map = BiMultiMap::from_iter([
+        ("alice", 0),
+        ("bob", 0),
+        ("bob", 1),
+        ("carol", 1),
+        ("dave", 2),
+        ("erin", 3),
+        ("erin", 4),
+    ]);
+    let component = map.component_of_left("carol").unwrap();
+
+    assert_eq!(component.lefts.len(), 3);
+    assert_eq!(component.rights.len(), 2);
+    assert_eq!(component.lefts[0], &"carol");
+}
+
+#[test]
+fn components() {
+    let map = BiMultiMap::from_iter([
+        ("alice", 0),
+        ("bob", 0),
+        ("bob", 1),
+        ("carol", 1),
+        ("dave", 2),
+        ("erin", 3),
+        ("erin", 4),
+    ]);
+    let components = map.components().map(as_sets).collect::<Vec<_>>();
+
+    assert_eq!(components.len(), 3);
+
+    for expected in [
+        (Set::from(["alice", "bob", "carol"]), Set::from([0, 1])),
+        (Set::from(["dave"]), Set::from([2])),
+        (Set::from(["erin"]), Set::from([3, 4])),
+    ] {
+        assert!(components.contains(&expected));
+    }
+}
+
+#[test]
+fn components_of_empty_map() {
+    let map = BiMultiMap::<i32, i32>::new();
+
+    assert_eq!(map.components().next(), None);
+}
@@ -0,0 +1,46 @@
+use bimultimap::BiMultiMap;
+
+#[test]
+fn contains() {
+    let map = BiMultiMap::from_iter([("a", 0), ("a", 1), ("b", 1)]);
+
+    assert!(map.contains("a", &0));
+    assert!(map.contains("b", &1));
+    assert!(!map.contains("b", &0));
+    assert!(!map.contains("c", &0));
+}
+
+#[test]
+fn contains_left_and_right() {
+    let map = BiMultiMap::from_iter([("a", 0)]);
+
+    assert!(map.contains_left("a"));
+    assert!(!map.contains_left("b"));
+    assert!(map.contains_right(&0));
+    assert!(!map.contains_right(&1));
+}
+
+#[test]
+fn degree() {
+    let map = BiMultiMap::from_iter([("a", 0), ("a", 1), ("a", 2), ("b", 1)]);
+
+    assert_eq!(map.left_degree("a"), 3);
+    assert_eq!(map.left_degree("b"), 1);
+    assert_eq!(map.left_degree("c"), 0);
+    assert_eq!(map.right_degree(&1), 2);
+    assert_eq!(map.right_degree(&3), 0);
+}
+
+#[test]
+fn left_and_right_len() {
+    let mut map = BiMultiMap::from_iter([("a", 0), ("a", 1), ("a", 2), ("b", 1)]);
+
+    assert_eq!(map.len(), 4);
+    assert_eq!(map.left_len(), 2);
+    assert_eq!(map.right_len(), 3);
+
+    map.remove_left("a");
+
+    assert_eq!(map.left_len(), 1);
+    assert_eq!(map.right_len(), 1);
+}
@@ -1,2 +1,131 @@
-#[test]
-fn basic_entry() {}
+mod left_entry {
+    use bimultimap::{BiMultiMap, LeftEntry, Rc, Set};
+
+    #[test]
+    fn basic_entry() {
+        let mut map = BiMultiMap::new();
+
+        map.left_entry("a").insert_value("b");
+
+        assert_eq!(map.get_left(&"a"), Some(&Set::from([Rc::new("b")])));
+        assert_eq!(map.get_right(&"b"), Some(&Set::from([Rc::new("a")])));
+        assert_eq!(map.len(), 1);
+    }
+
+    #[test]
+    fn occupied_and_vacant() {
+        let mut map = BiMultiMap::from_iter([("a", 0)]);
+
+        assert!(matches!(map.left_entry("a"), LeftEntry::Occupied(_)));
+        assert!(matches!(map.left_entry("b"), LeftEntry::Vacant(_)));
+        assert_eq!(map.left_entry("b").key(), &"b");
+    }
+
+    #[test]
+    fn or_insert_with_set() {
+        let mut map = BiMultiMap::from_iter([("a", 0)]);
+
+        map.left_entry("a").or_insert_with_set(|| Set::from([1, 2]));
+        map.left_entry("b").or_insert_with_set(|| Set::from([1, 2]));
+        map.left_entry("c").or_insert_with_set(Set::new);
+
+        assert_eq!(map.get_left(&"a"), Some(&Set::from([Rc::new(0)])));
+        assert_eq!(
+            map.get_left(&"b"),
+            Some(&Set::from([Rc::new(1), Rc::new(2)]))
+        );
+        assert_eq!(map.get_left(&"c"), None);
+        assert_eq!(map.get_right(&1), Some(&Set::from([Rc::new("b")])));
+        assert_eq!(map.len(), 3);
+    }
+
+    #[test]
+    fn insert_existing_value() {
+        let mut map = BiMultiMap::from_iter([("a", 0)]);
+
+        {
+            let mut entry = map.left_entry("a").insert_value(1);
+
+            assert!(!entry.insert_value(0));
+            assert_eq!(entry.get(), &Set::from([Rc::new(0), Rc::new(1)]));
+        }
+
+        assert_eq!(map.len(), 2);
+    }
+
+    #[test]
+    fn and_modify_remove_value() {
+        let mut map = BiMultiMap::from_iter([("a", 0), ("a", 1), ("b", 1)]);
+
+        map.left_entry("a").and_modify(|entry| {
+            assert!(entry.remove_value(&1));
+            assert!(!entry.remove_value(&2));
+        });
+
+        assert_eq!(map.get_left(&"a"), Some(&Set::from([Rc::new(0)])));
+        assert_eq!(map.get_right(&1), Some(&Set::from([Rc::new("b")])));
+        assert_eq!(map.len(), 2);
+    }
+
+    #[test]
+    fn remove_last_value() {
+        let mut map = BiMultiMap::from_iter([("a", 0)]);
+
+        map.left_entry("a").and_modify(|entry| {
+            entry.remove_value(&0);
+            assert!(entry.get().is_empty());
+        });
+
+        assert_eq!(map.get_left(&"a"), None);
+        assert_eq!(map.get_right(&0), None);
+        assert_eq!(map.len(), 0);
+    }
+
+    #[test]
+    fn remove_all() {
+        let mut map = BiMultiMap::from_iter([("a", 0), ("a", 1), ("b", 1)]);
+
+        assert_eq!(
+            map.left_entry("a").remove_all(),
+            Some(Set::from([Rc::new(0), Rc::new(1)]))
+        );
+        assert_eq!(map.left_entry("a").remove_all(), None);
+
+        assert_eq!(map.get_right(&0), None);
+        assert_eq!(map.get_right(&1), Some(&Set::from([Rc::new("b")])));
+        assert_eq!(map.len(), 1);
+    }
+}
+
+mod right_entry {
+    use bimultimap::{BiMultiMap, Rc, Set};
+
+    #[test]
+    fn basic_entry() {
+        let mut map = BiMultiMap::new();
+
+        map.right_entry("b").insert_value("a");
+
+        assert_eq!(map.get_left(&"a"), Some(&Set::from([Rc::new("b")])));
+        assert_eq!(map.get_right(&"b"), Some(&Set::from([Rc::new("a")])));
+        assert_eq!(map.len(), 1);
+    }
+
+    #[test]
+    fn and_modify() {
+        let mut map = BiMultiMap::from_iter([(0, "a"), (1, "a")]);
+
+        map.right_entry("a").and_modify(|entry| {
+            entry.insert_value(2);
+            entry.remove_value(&0);
+        });
+
+        assert_eq!(
+            map.get_right(&"a"),
+            Some(&Set::from([Rc::new(1), Rc::new(2)]))
+        );
+        assert_eq!(map.get_left(&0), None);
+        assert_eq!(map.get_left(&2), Some(&Set::from([Rc::new("a")])));
+        assert_eq!(map.len(), 2);
+    }
+}
@@ -0,0 +1,103 @@
+mod borrowed {
+    use bimultimap::{BiMultiMap, Rc, Set};
+
+    #[test]
+    fn get() {
+        let map = BiMultiMap::from_iter([
+            ("a".to_string(), "x".to_string()),
+            ("a".to_string(), "y".to_string()),
+            ("b".to_string(), "y".to_string()),
+        ]);
+
+        assert_eq!(
+            map.get_left("b"),
+            Some(&Set::from([Rc::new("y".to_string())]))
+        );
+        assert_eq!(
+            map.get_right("x"),
+            Some(&Set::from([Rc::new("a".to_string())]))
+        );
+        assert_eq!(map.get_left("c"), None);
+    }
+
+    #[test]
+    fn get_one() {
+        let map = BiMultiMap::from_iter([
+            ("a".to_string(), "x".to_string()),
+            ("a".to_string(), "y".to_string()),
+            ("b".to_string(), "y".to_string()),
+        ]);
+
+        assert_eq!(map.get_one_left("b"), Some(&"y".to_string()));
+        assert_eq!(map.get_one_right("x"), Some(&"a".to_string()));
+        assert_eq!(map.get_one_right("z"), None);
+    }
+
+    #[test]
+    fn get_vec() {
+        let map = BiMultiMap::from_iter([
+            ("a".to_string(), "x".to_string()),
+            ("a".to_string(), "y".to_string()),
+            ("b".to_string(), "y".to_string()),
+        ]);
+
+        assert_eq!(map.get_left_vec("a").map(|v| v.len()), Some(2));
+        assert_eq!(map.get_right_vec("x"), Some(vec![&"a".to_string()]));
+    }
+
+    #[test]
+    fn remove() {
+        let mut map = BiMultiMap::from_iter([
+            ("a".to_string(), "x".to_string()),
+            ("a".to_string(), "y".to_string()),
+            ("b".to_string(), "y".to_string()),
+        ]);
+
+        assert!(map.remove("a", "y"));
+        assert!(!map.remove("a", "y"));
+        assert_eq!(map.remove_left("b").map(|set| set.len()), Some(1));
+        assert_eq!(map.remove_right("x").map(|set| set.len()), Some(1));
+        assert!(map.is_empty());
+        map.check_invariants();
+    }
+
+    #[test]
+    fn entry_remove_value() {
+        let mut map = BiMultiMap::from_iter([
+            ("a".to_string(), "x".to_string()),
+            ("a".to_string(), "y".to_string()),
+            ("b".to_string(), "y".to_string()),
+        ]);
+
+        map.left_entry("a".to_string()).and_modify(|entry| {
+            entry.remove_value("x");
+        });
+
+        assert_eq!(map.get_right("x"), None);
+        assert_eq!(map.len(), 2);
+        map.check_invariants();
+    }
+
+    #[test]
+    #[cfg(feature = "btreemap")]
+    fn range() {
+        use std::ops::Bound;
+
+        let map = BiMultiMap::from_iter([
+            ("a".to_string(), "x".to_string()),
+            ("a".to_string(), "y".to_string()),
+            ("b".to_string(), "y".to_string()),
+        ]);
+
+        assert_eq!(
+            map.range_left::<str>((Bound::Included("b"), Bound::Unbounded))
+                .collect::<Vec<_>>(),
+            vec![(&"b".to_string(), &"y".to_string())]
+        );
+        assert_eq!(
+            map.range_right::<str>((Bound::Unbounded, Bound::Excluded("y")))
+                .count(),
+            1
+        );
+    }
+}
@@ -0,0 +1,143 @@
+use bimultimap::{BiMultiMap, Set};
+
+fn sorted_components(map: &BiMultiMap<i32, i32>) -> Vec<Vec<i32>> {
+    let mut components = map
+        .strongly_connected_components()
+        .into_iter()
+        .map(|component| {
+            let mut component = component.into_iter().copied().collect::<Vec<_>>();
+            component.sort();
+            component
+        })
+        .collect::<Vec<_>>();
+    components.sort();
+    components
+}
+
+#[test]
+fn reachable() {
+    let map = BiMultiMap::from_iter([
+        ("app", "http"),
+        ("app", "log"),
+        ("http", "io"),
+        ("log", "io"),
+        ("io", "core"),
+    ]);
+
+    assert_eq!(
+        map.reachable_from_left("app").copied().collect::<Set<_>>(),
+        Set::from(["http", "log", "io", "core"])
+    );
+    assert_eq!(
+        map.reachable_from_right("io").copied().collect::<Set<_>>(),
+        Set::from(["http", "log", "app"])
+    );
+    assert_eq!(map.reachable_from_left("core").next(), None);
+    assert_eq!(map.reachable_from_left("missing").next(), None);
+    // `io` is reachable through two paths, but only yielded once
+    assert_eq!(map.reachable_from_left("app").count(), 4);
+}
+
+#[test]
+fn reachable_is_breadth_first() {
+    let map = BiMultiMap::from_iter([(0, 1), (1, 2), (2, 3)]);
+
+    assert_eq!(
+        map.reachable_from_left(&0).collect::<Vec<_>>(),
+        vec![&1, &2, &3]
+    );
+}
+
+#[test]
+fn reachable_in_cycle() {
+    let map = BiMultiMap::from_iter([(0, 1), (1, 0)]);
+
+    assert_eq!(
+        map.reachable_from_left(&0).collect::<Vec<_>>(),
+        vec![&1, &0]
+    );
+}
+
+#[test]
+fn transitive_closure() {
+    let depends_on = BiMultiMap::from_iter([
+        ("app", "http"),
+        ("app", "log"),
+        ("http", "io"),
+        ("log", "io"),
+        ("io", "core"),
+    ]);
+    let closure = depends_on.transitive_closure();
+
+    assert_eq!(closure.left_degree("app"), 4);
+    assert_eq!(closure.left_degree("http"), 2);
+    assert_eq!(closure.right_degree("core"), 4);
+    assert_eq!(closure.len(), 4 + 2 + 2 + 1);
+    closure.check_invariants();
+}
+
+#[test]
+fn topological_order() {
+    let map = BiMultiMap::from_iter([
+        ("app", "http"),
+        ("app", "log"),
+        ("http", "io"),
+        ("log", "io"),
+        ("io", "core"),
+    ]);
+    let order = map.topological_order().unwrap();
+
+    assert_eq!(order.len(), 5);
+
+    let position = |value| order.iter().position(|&&other| other == value);
+
+    for (left, right) in map.iter_ref() {
+        assert!(position(*left) < position(*right));
+    }
+
+    assert!(!map.has_cycle());
+}
+
+#[test]
+fn cycle() {
+    let mut map = BiMultiMap::from_iter([
+        ("app", "http"),
+        ("app", "log"),
+        ("http", "io"),
+        ("log", "io"),
+        ("io", "core"),
+    ]);
+    map.insert("core", "app");
+
+    assert!(map.has_cycle());
+    assert_eq!(map.topological_order(), None);
+
+    let self_loop = BiMultiMap::from_iter([(0, 0)]);
+
+    assert!(self_loop.has_cycle());
+}
+
+#[test]
+fn strongly_connected_components() {
+    let map = BiMultiMap::from_iter([
+        (0, 1),
+        (1, 2),
+        (2, 0),
+        (2, 3),
+        (3, 4),
+        (4, 3),
+        (5, 5),
+    ]);
+
+    assert_eq!(
+        sorted_components(&map),
+        vec![vec![0, 1, 2], vec![3, 4], vec![5]]
+    );
+}
+
+#[test]
+fn strongly_connected_components_without_cycle() {
+    let map = BiMultiMap::from_iter([(0, 1), (1, 2)]);
+
+    assert_eq!(sorted_components(&map), vec![vec![0], vec![1], vec![2]]);
+}
@@ -1,4 +1,4 @@
-use bimultimap::{BiMultiMap, HashSet, Rc};
+use bimultimap::{BiMultiMap, Rc, Set};
 
 #[test]
 pub fn basic_insert() {
@@ -6,7 +6,7 @@ pub fn basic_insert() {
 
     map.insert("a", "b");
 
-    assert_eq!(map.get_left(&"a"), Some(&HashSet::from([Rc::new("b")])));
+    assert_eq!(map.get_left(&"a"), Some(&Set::from([Rc::new("b")])));
     assert_eq!(map.len(), 1)
 }
 
@@ -19,13 +19,13 @@ pub fn multiple_left_insert() {
     map.insert("c", "b");
     map.insert("d", "b");
 
-    assert_eq!(map.get_left(&"a"), Some(&HashSet::from([Rc::new("b")])));
-    assert_eq!(map.get_left(&"b"), Some(&HashSet::from([Rc::new("b")])));
-    assert_eq!(map.get_left(&"c"), Some(&HashSet::from([Rc::new("b")])));
-    assert_eq!(map.get_left(&"d"), Some(&HashSet::from([Rc::new("b")])));
+    assert_eq!(map.get_left(&"a"), Some(&Set::from([Rc::new("b")])));
+    assert_eq!(map.get_left(&"b"), Some(&Set::from([Rc::new("b")])));
+    assert_eq!(map.get_left(&"c"), Some(&Set::from([Rc::new("b")])));
+    assert_eq!(map.get_left(&"d"), Some(&Set::from([Rc::new("b")])));
     assert_eq!(
         map.get_right(&"b"),
-        Some(&HashSet::from([
+        Some(&Set::from([
             Rc::new("a"),
             Rc::new("b"),
             Rc::new("c"),
@@ -44,13 +44,13 @@ pub fn multiple_right_insert() {
     map.insert("b", "c");
     map.insert("b", "d");
 
-    assert_eq!(map.get_right(&"a"), Some(&HashSet::from([Rc::new("b")])));
-    assert_eq!(map.get_right(&"b"), Some(&HashSet::from([Rc::new("b")])));
-    assert_eq!(map.get_right(&"c"), Some(&HashSet::from([Rc::new("b")])));
-    assert_eq!(map.get_right(&"d"), Some(&HashSet::from([Rc::new("b")])));
+    assert_eq!(map.get_right(&"a"), Some(&Set::from([Rc::new("b")])));
+    assert_eq!(map.get_right(&"b"), Some(&Set::from([Rc::new("b")])));
+    assert_eq!(map.get_right(&"c"), Some(&Set::from([Rc::new("b")])));
+    assert_eq!(map.get_right(&"d"), Some(&Set::from([Rc::new("b")])));
     assert_eq!(
         map.get_left(&"b"),
-        Some(&HashSet::from([
+        Some(&Set::from([
             Rc::new("a"),
             Rc::new("b"),
             Rc::new("c"),
@@ -71,19 +71,19 @@ pub fn multiple_insert_right_and_left() {
 
     assert_eq!(
         map.get_right(&"b"),
-        Some(&HashSet::from([Rc::new("a"), Rc::new("b")]))
+        Some(&Set::from([Rc::new("a"), Rc::new("b")]))
     );
     assert_eq!(
         map.get_right(&"a"),
-        Some(&HashSet::from([Rc::new("a"), Rc::new("b")]))
+        Some(&Set::from([Rc::new("a"), Rc::new("b")]))
     );
     assert_eq!(
         map.get_left(&"b"),
-        Some(&HashSet::from([Rc::new("a"), Rc::new("b")]))
+        Some(&Set::from([Rc::new("a"), Rc::new("b")]))
     );
     assert_eq!(
         map.get_left(&"a"),
-        Some(&HashSet::from([Rc::new("a"), Rc::new("b")]))
+        Some(&Set::from([Rc::new("a"), Rc::new("b")]))
     );
     assert_eq!(map.len(), 4)
 }
@@ -97,6 +97,105 @@ pub fn same_value_insert() {
     map.insert("a", "a");
     map.insert("a", "a");
 
-    assert_eq!(map.get_right(&"a"), Some(&HashSet::from([Rc::new("a")])));
+    assert_eq!(map.get_right(&"a"), Some(&Set::from([Rc::new("a")])));
     assert_eq!(map.len(), 1)
 }
+
+#[test]
+pub fn insert_return_value() {
+    let mut map = BiMultiMap::new();
+
+    assert!(map.insert("a", "a"));
+    assert!(map.insert("a", "b"));
+    assert!(!map.insert("a", "a"));
+    assert!(map.insert("b", "a"));
+    assert_eq!(map.len(), 3)
+}
+
+#[test]
+pub fn shared_allocation() {
+    let mut map = BiMultiMap::new();
+
+    map.insert(0, 'a');
+    map.insert(1, 'a');
+    map.insert(0, 'b');
+
+    let a_rc = map.right_values().find(|right| ***right == 'a').unwrap();
+
+    // One for the key of the right map, one per left key mapped to it
+    assert_eq!(Rc::strong_count(a_rc), 3);
+    assert!(
+        map.get_left(&0)
+            .unwrap()
+            .iter()
+            .any(|right| Rc::ptr_eq(right, a_rc))
+    );
+    assert!(
+        map.get_left(&1)
+            .unwrap()
+            .iter()
+            .any(|right| Rc::ptr_eq(right, a_rc))
+    );
+}
+
+#[test]
+pub fn extend() {
+    let mut map = BiMultiMap::from_iter([("a", 0)]);
+
+    map.extend([("a", 0), ("a", 1), ("b", 1)]);
+
+    assert_eq!(map.len(), 3);
+    assert_eq!(
+        map.get_right(&1),
+        Some(&Set::from([Rc::new("a"), Rc::new("b")]))
+    );
+    map.check_invariants();
+}
+
+#[test]
+pub fn extend_copied() {
+    let pairs = [('a', 0), ('b', 0)];
+    let mut map = BiMultiMap::<char, i32>::new();
+
+    map.extend(pairs.iter().map(|(left, right)| (left, right)));
+
+    assert_eq!(map.len(), 2);
+    assert_eq!(map.right_degree(&0), 2);
+}
+
+#[test]
+pub fn extend_left() {
+    let mut map = BiMultiMap::from_iter([("a", 0), ("b", 1)]);
+
+    assert_eq!(map.extend_left("a", 0..4), 3);
+    assert_eq!(map.extend_left("a", 0..4), 0);
+
+    assert_eq!(map.len(), 5);
+    assert_eq!(map.left_degree("a"), 4);
+    assert_eq!(
+        map.get_right(&1),
+        Some(&Set::from([Rc::new("a"), Rc::new("b")]))
+    );
+    map.check_invariants();
+}
+
+#[test]
+pub fn extend_right() {
+    let mut map = BiMultiMap::from_iter([(0, "a")]);
+
+    assert_eq!(map.extend_right("a", [1, 2]), 2);
+
+    assert_eq!(map.len(), 3);
+    assert_eq!(map.right_degree("a"), 3);
+    map.check_invariants();
+}
+
+#[test]
+pub fn extend_left_empty() {
+    let mut map = BiMultiMap::<&str, i32>::new();
+
+    assert_eq!(map.extend_left("a", []), 0);
+
+    assert!(!map.contains_left("a"));
+    map.check_invariants();
+}
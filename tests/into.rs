@@ -0,0 +1,102 @@
+use {
+    bimultimap::{BiMultiMap, Map, Set},
+    std::{cell::Cell, collections::BTreeSet},
+};
+
+thread_local! {
+    static CLONES: Cell<usize> = const { Cell::new(0) };
+}
+
+/// A value counting how many times it has been cloned
+#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct Counted(u8);
+
+impl Clone for Counted {
+    fn clone(&self) -> Self {
+        CLONES.set(CLONES.get() + 1);
+        Counted(self.0)
+    }
+}
+
+fn clones() -> usize {
+    CLONES.get()
+}
+
+#[test]
+fn into_pairs() {
+    let map = BiMultiMap::from_iter([(0, 'a'), (0, 'b'), (1, 'a')]);
+
+    let mut pairs = map.into_pairs();
+
+    assert_eq!(pairs.len(), 3);
+    assert_eq!(
+        pairs.by_ref().collect::<BTreeSet<_>>(),
+        BTreeSet::from([(0, 'a'), (0, 'b'), (1, 'a')])
+    );
+    assert_eq!(pairs.next(), None);
+}
+
+#[test]
+fn into_pairs_clones_only_shared_values() {
+    let map = BiMultiMap::from_iter([
+        (Counted(0), Counted(10)),
+        (Counted(1), Counted(11)),
+        (Counted(2), Counted(10)),
+        (Counted(2), Counted(12)),
+    ]);
+    let before = clones();
+
+    let pairs = map.into_pairs().collect::<BTreeSet<_>>();
+
+    // `Counted(10)` and `Counted(2)` are both part of 2 mappings
+    assert_eq!(clones() - before, 2);
+    assert_eq!(pairs.len(), 4);
+}
+
+#[test]
+fn into_pairs_of_a_clone() {
+    let map = BiMultiMap::from_iter([(Counted(0), Counted(10))]);
+    let cloned = map.clone();
+    let before = clones();
+
+    assert_eq!(
+        map.into_pairs().collect::<Vec<_>>(),
+        vec![(Counted(0), Counted(10))]
+    );
+    assert_eq!(clones() - before, 2);
+    assert_eq!(cloned.len(), 1);
+}
+
+#[test]
+fn into_left_map() {
+    let map = BiMultiMap::from_iter([
+        (Counted(0), Counted(10)),
+        (Counted(1), Counted(10)),
+        (Counted(1), Counted(11)),
+    ]);
+    let before = clones();
+
+    let left_map = map.into_left_map();
+
+    assert_eq!(clones() - before, 1);
+    assert_eq!(
+        left_map,
+        Map::from([
+            (Counted(0), Set::from([Counted(10)])),
+            (Counted(1), Set::from([Counted(10), Counted(11)])),
+        ])
+    );
+}
+
+#[test]
+fn into_right_map() {
+    let map = BiMultiMap::from_iter([(0, 'a'), (0, 'b'), (1, 'a')]);
+
+    assert_eq!(
+        map.into_right_map(),
+        Map::from([
+            ('a', Set::from([0, 1])),
+            ('b', Set::from([0]))
+        ])
+    );
+}
@@ -0,0 +1,100 @@
+use {
+    bimultimap::{BiMultiMap, Set},
+    proptest::prelude::*,
+    std::collections::BTreeSet,
+};
+
+#[derive(Debug, Clone)]
+enum Operation {
+    Insert(u8, u8),
+    Remove(u8, u8),
+    RemoveLeft(u8),
+    RemoveRight(u8),
+    SetLeft(u8, Vec<u8>),
+    SetRight(u8, Vec<u8>),
+    LeftEntryInsert(u8, u8),
+    LeftEntryRemove(u8, u8),
+    RightEntryRemoveAll(u8),
+}
+
+fn key() -> impl Strategy<Value = u8> {
+    0u8..8
+}
+
+fn operation() -> impl Strategy<Value = Operation> {
+    prop_oneof![
+        (key(), key()).prop_map(|(l, r)| Operation::Insert(l, r)),
+        (key(), key()).prop_map(|(l, r)| Operation::Remove(l, r)),
+        key().prop_map(Operation::RemoveLeft),
+        key().prop_map(Operation::RemoveRight),
+        (key(), prop::collection::vec(key(), 0..5)).prop_map(|(l, r)| Operation::SetLeft(l, r)),
+        (key(), prop::collection::vec(key(), 0..5)).prop_map(|(r, l)| Operation::SetRight(r, l)),
+        (key(), key()).prop_map(|(l, r)| Operation::LeftEntryInsert(l, r)),
+        (key(), key()).prop_map(|(l, r)| Operation::LeftEntryRemove(l, r)),
+        key().prop_map(Operation::RightEntryRemoveAll),
+    ]
+}
+
+/// Applies `operation` both on the [BiMultiMap] and on a naive set of pairs
+fn apply(map: &mut BiMultiMap<u8, u8>, model: &mut BTreeSet<(u8, u8)>, operation: Operation) {
+    match operation {
+        Operation::Insert(l, r) => {
+            map.insert(l, r);
+            model.insert((l, r));
+        },
+        Operation::Remove(l, r) => {
+            assert_eq!(map.remove(&l, &r), model.remove(&(l, r)));
+        },
+        Operation::RemoveLeft(l) => {
+            map.remove_left(&l);
+            model.retain(|(left, _)| *left != l);
+        },
+        Operation::RemoveRight(r) => {
+            map.remove_right(&r);
+            model.retain(|(_, right)| *right != r);
+        },
+        Operation::SetLeft(l, rights) => {
+            model.retain(|(left, _)| *left != l);
+            model.extend(rights.iter().map(|r| (l, *r)));
+            map.set_left(l.into(), Set::from_iter(rights));
+        },
+        Operation::SetRight(r, lefts) => {
+            model.retain(|(_, right)| *right != r);
+            model.extend(lefts.iter().map(|l| (*l, r)));
+            map.set_right(r.into(), Set::from_iter(lefts));
+        },
+        Operation::LeftEntryInsert(l, r) => {
+            map.left_entry(l).insert_value(r);
+            model.insert((l, r));
+        },
+        Operation::LeftEntryRemove(l, r) => {
+            map.left_entry(l).and_modify(|entry| {
+                entry.remove_value(&r);
+            });
+            model.remove(&(l, r));
+        },
+        Operation::RightEntryRemoveAll(r) => {
+            map.right_entry(r).remove_all();
+            model.retain(|(_, right)| *right != r);
+        },
+    }
+}
+
+proptest! {
+    #[test]
+    fn random_operations(operations in prop::collection::vec(operation(), 0..64)) {
+        let mut map = BiMultiMap::new();
+        let mut model = BTreeSet::new();
+
+        for operation in operations {
+            apply(&mut map, &mut model, operation);
+
+            map.check_invariants();
+            prop_assert_eq!(map.len(), model.len());
+            prop_assert_eq!(
+                map.iter_ref().map(|(l, r)| (*l, *r)).collect::<BTreeSet<_>>(),
+                model.clone()
+            );
+        }
+    }
+}
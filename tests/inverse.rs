@@ -0,0 +1,102 @@
+use bimultimap::{BiMultiMap, BiMultiMapRead, Set};
+
+#[test]
+fn into_inverse() {
+    let map = BiMultiMap::from_iter([("a", 0), ("a", 1), ("b", 1)]);
+    let inverse = map.clone().into_inverse();
+
+    assert_eq!(inverse.len(), 3);
+    assert_eq!(inverse.left_degree(&1), 2);
+    assert_eq!(inverse.get_right_vec("b"), Some(vec![&1]));
+    assert!(inverse.contains(&0, "a"));
+    inverse.check_invariants();
+
+    assert_eq!(inverse.into_inverse(), map);
+}
+
+#[test]
+fn as_inverse() {
+    let map = BiMultiMap::from_iter([("a", 0), ("a", 1), ("b", 1)]);
+    let inverse = map.as_inverse();
+
+    assert_eq!(inverse.len(), 3);
+    assert_eq!(inverse.left_len(), 2);
+    assert_eq!(inverse.right_len(), 2);
+    assert!(inverse.contains(&1, "b"));
+    assert!(!inverse.contains(&0, "b"));
+    assert!(inverse.contains_left(&0));
+    assert!(inverse.contains_right("a"));
+    assert_eq!(inverse.left_degree(&1), 2);
+    assert_eq!(inverse.right_degree("a"), 2);
+    assert_eq!(inverse.get_one_left(&0), Some(&"a"));
+    assert_eq!(inverse.get_right_vec("b"), Some(vec![&1]));
+    assert_eq!(inverse.iter_left(&1).len(), 2);
+    assert_eq!(inverse.iter_right("a").len(), 2);
+    assert_eq!(
+        inverse.left_values().map(|left| **left).collect::<Set<_>>(),
+        Set::from([0, 1])
+    );
+    assert_eq!(
+        inverse
+            .iter_ref()
+            .map(|(&l, &r)| (l, r))
+            .collect::<Set<_>>(),
+        Set::from([(0, "a"), (1, "a"), (1, "b")])
+    );
+    assert_eq!(inverse.as_inverse(), &map);
+}
+
+/// Written once for the left side, and reused for the right one through the inverse view
+fn left_degrees<M>(map: M) -> Vec<(M::Left, usize)>
+where
+    M: BiMultiMapRead,
+    M::Left: Copy + Ord,
+{
+    let mut degrees = map
+        .left_values()
+        .map(|left| (**left, map.left_degree(&**left)))
+        .collect::<Vec<_>>();
+    degrees.sort();
+    degrees
+}
+
+#[test]
+fn generic_over_sides() {
+    let map = BiMultiMap::from_iter([("a", 0), ("a", 1), ("b", 1)]);
+
+    assert_eq!(left_degrees(&map), vec![("a", 2), ("b", 1)]);
+    assert_eq!(left_degrees(map.as_inverse()), vec![(0, 1), (1, 2)]);
+}
+
+/// Goes through every method of the trait, to compare both sides
+fn read_api<M>(map: M) -> (usize, usize, usize, bool, Option<Vec<i32>>, usize)
+where
+    M: BiMultiMapRead<Left = &'static str, Right = i32>,
+{
+    let mut rights = map
+        .get_left_vec("a")
+        .map(|rights| rights.into_iter().copied().collect::<Vec<_>>());
+    if let Some(rights) = &mut rights {
+        rights.sort();
+    }
+
+    (
+        map.len(),
+        map.left_len(),
+        map.right_len(),
+        map.contains("a", &1) && !map.contains("b", &0) && map.contains_right(&0),
+        rights,
+        map.iter_right(&1).len() + map.right_degree(&1) + map.right_values().len(),
+    )
+}
+
+#[test]
+fn same_read_api() {
+    let map = BiMultiMap::from_iter([("a", 0), ("a", 1), ("b", 1)]);
+    let inverse = BiMultiMap::from([(0, "a"), (1, "a"), (1, "b")]);
+
+    assert_eq!(read_api(&map), read_api(inverse.as_inverse()));
+    assert_eq!(read_api(&map), (3, 2, 2, true, Some(vec![0, 1]), 6));
+    assert!(!BiMultiMapRead::is_empty(&map.as_inverse()));
+    assert_eq!(map.as_inverse().get_one_right("b"), Some(&1));
+}
@@ -17,6 +17,23 @@ mod into_iter {
         assert!(imap.next().is_none());
     }
 
+    #[test]
+    pub fn not_clone() {
+        #[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        struct Id(u8);
+
+        let map = BiMultiMap::from_iter([(Id(0), Id(1)), (Id(0), Id(2))]);
+        let mut count = 0;
+
+        for (left, right) in map {
+            assert_eq!(*left, Id(0));
+            assert_ne!(*right, Id(0));
+            count += 1;
+        }
+
+        assert_eq!(count, 2);
+    }
+
     #[test]
     pub fn no_value() {
         let map = BiMultiMap::<String, String>::new();
@@ -116,10 +133,7 @@ mod iter_ref {
 }
 
 mod iter {
-    use {
-        bimultimap::{BiMultiMap, Rc},
-        std::{hash::Hash, sync::Mutex},
-    };
+    use bimultimap::{BiMultiMap, Rc};
 
     #[test]
     pub fn basic() {
@@ -177,7 +191,10 @@ mod iter {
     }
 
     #[test]
+    #[cfg(not(feature = "btreemap"))]
     pub fn modifying_values() {
+        use std::{hash::Hash, sync::Mutex};
+
         #[derive(Debug)]
         struct HashMutex(Mutex<i32>);
 
@@ -187,9 +204,7 @@ mod iter {
             }
         }
 
-        impl Eq for HashMutex {
-            fn assert_receiver_is_total_eq(&self) {}
-        }
+        impl Eq for HashMutex {}
 
         impl Hash for HashMutex {
             fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
@@ -217,14 +232,14 @@ mod iter {
 }
 
 mod from_iter {
-    use bimultimap::{BiMultiMap, HashSet, Rc};
+    use bimultimap::{BiMultiMap, Rc, Set};
 
     #[test]
     fn one_iter() {
         let map = BiMultiMap::from_iter([(0, 1)]);
 
-        assert_eq!(map.get_right(&1), Some(&HashSet::from([Rc::new(0)])));
-        assert_eq!(map.get_left(&0), Some(&HashSet::from([Rc::new(1)])));
+        assert_eq!(map.get_right(&1), Some(&Set::from([Rc::new(0)])));
+        assert_eq!(map.get_left(&0), Some(&Set::from([Rc::new(1)])));
         assert_eq!(map.len(), 1);
     }
 
@@ -232,34 +247,31 @@ mod from_iter {
     fn multiple_iter() {
         let map = BiMultiMap::from_iter([(0, 1), (1, 2), (2, 1), (1, 1)]);
 
-        assert_eq!(map.get_left(&0), Some(&HashSet::from([Rc::new(1)])));
-        assert_eq!(
-            map.get_left(&1),
-            Some(&HashSet::from([Rc::new(1), Rc::new(2)]))
-        );
-        assert_eq!(map.get_left(&2), Some(&HashSet::from([Rc::new(1)])));
+        assert_eq!(map.get_left(&0), Some(&Set::from([Rc::new(1)])));
+        assert_eq!(map.get_left(&1), Some(&Set::from([Rc::new(1), Rc::new(2)])));
+        assert_eq!(map.get_left(&2), Some(&Set::from([Rc::new(1)])));
 
         assert_eq!(
             map.get_right(&1),
-            Some(&HashSet::from([Rc::new(0), Rc::new(1), Rc::new(2)]))
+            Some(&Set::from([Rc::new(0), Rc::new(1), Rc::new(2)]))
         );
-        assert_eq!(map.get_right(&2), Some(&HashSet::from([Rc::new(1)])));
+        assert_eq!(map.get_right(&2), Some(&Set::from([Rc::new(1)])));
         assert_eq!(map.len(), 4);
     }
 
     #[test]
     fn reverse_left_right() {
         let map = BiMultiMap::from_iter([(0, 1)].into_iter().map(|(a, b)| (b, a)));
-        assert_eq!(map.get_left(&1), Some(&HashSet::from([Rc::new(0)])));
-        assert_eq!(map.get_right(&0), Some(&HashSet::from([Rc::new(1)])));
+        assert_eq!(map.get_left(&1), Some(&Set::from([Rc::new(0)])));
+        assert_eq!(map.get_right(&0), Some(&Set::from([Rc::new(1)])));
         assert_eq!(map.len(), 1);
     }
 
     #[test]
     fn repeating() {
         let map = BiMultiMap::from_iter([(0, 0), (0, 0)]);
-        assert_eq!(map.get_left(&0), Some(&HashSet::from([Rc::new(0)])));
-        assert_eq!(map.get_right(&0), Some(&HashSet::from([Rc::new(0)])));
+        assert_eq!(map.get_left(&0), Some(&Set::from([Rc::new(0)])));
+        assert_eq!(map.get_right(&0), Some(&Set::from([Rc::new(0)])));
         assert_eq!(map.len(), 1);
     }
 
@@ -279,3 +291,178 @@ mod from_iter {
         assert!(imap.next().is_none());
     }
 }
+
+mod iter_left_right {
+    use {bimultimap::BiMultiMap, std::collections::BTreeSet};
+
+    #[test]
+    fn iter_left() {
+        let map = BiMultiMap::from_iter([("a", 0), ("a", 1), ("b", 1)]);
+
+        assert_eq!(
+            map.iter_left("a").collect::<BTreeSet<_>>(),
+            BTreeSet::from([&0, &1])
+        );
+        assert_eq!(map.iter_left("b").collect::<Vec<_>>(), vec![&1]);
+        assert_eq!(map.iter_left("c").next(), None);
+    }
+
+    #[test]
+    fn iter_right() {
+        let map = BiMultiMap::from_iter([("a", 0), ("a", 1), ("b", 1)]);
+
+        assert_eq!(
+            map.iter_right(&1).collect::<BTreeSet<_>>(),
+            BTreeSet::from([&"a", &"b"])
+        );
+        assert_eq!(map.iter_right(&2).next(), None);
+    }
+
+    #[test]
+    fn exact_size() {
+        let map = BiMultiMap::from_iter([("a", 0), ("a", 1), ("a", 2)]);
+
+        let mut iter = map.iter_left("a");
+
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        iter.next();
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.clone().count(), 2);
+        assert_eq!(map.iter_left("b").size_hint(), (0, Some(0)));
+    }
+}
+
+mod iterator_types {
+    use {
+        bimultimap::{BiMultiMap, Iter, IterRef, LeftKeys},
+        std::collections::BTreeSet,
+    };
+
+    struct Holder<'a> {
+        iter: IterRef<'a, u8, char>,
+    }
+
+    #[test]
+    fn exact_size() {
+        let map = BiMultiMap::from_iter([(0, 'a'), (0, 'b'), (1, 'a')]);
+
+        let mut iter: Iter<'_, u8, char> = map.iter();
+
+        assert_eq!(iter.len(), 3);
+        iter.next();
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+        assert_eq!(map.iter_ref().len(), 3);
+        assert_eq!(map.clone().into_iter().len(), 3);
+
+        let keys: LeftKeys<'_, u8, char> = map.left_values();
+        assert_eq!(keys.len(), 2);
+        assert_eq!(map.right_values().len(), 2);
+    }
+
+    #[test]
+    fn fused() {
+        let map = BiMultiMap::from_iter([(0, 'a')]);
+
+        let mut iter = map.iter_ref();
+
+        assert!(iter.next().is_some());
+        assert!(iter.next().is_none());
+        assert!(iter.next().is_none());
+        assert_eq!(iter.len(), 0);
+    }
+
+    #[test]
+    fn clone() {
+        let map = BiMultiMap::from_iter([(0, 'a'), (0, 'b'), (1, 'a')]);
+
+        let mut holder = Holder {
+            iter: map.iter_ref(),
+        };
+        holder.iter.next();
+
+        let cloned = holder.iter.clone();
+
+        assert_eq!(
+            cloned.collect::<BTreeSet<_>>(),
+            holder.iter.collect::<BTreeSet<_>>()
+        );
+    }
+
+    #[test]
+    fn for_loop_by_ref() {
+        let map = BiMultiMap::from_iter([(0, 'a'), (0, 'b'), (1, 'a')]);
+
+        let mut count = 0;
+
+        for (left, right) in &map {
+            assert!(map.contains(&**left, &**right));
+            count += 1;
+        }
+
+        assert_eq!(count, 3);
+    }
+}
+
+mod iter_sorted {
+    use bimultimap::BiMultiMap;
+
+    #[test]
+    fn sorted_by_left_then_right() {
+        let map = BiMultiMap::from_iter([
+            (2, 'a'),
+            (0, 'c'),
+            (1, 'b'),
+            (0, 'a'),
+            (2, 'b'),
+        ]);
+
+        assert_eq!(
+            map.iter_sorted().collect::<Vec<_>>(),
+            vec![
+                (&0, &'a'),
+                (&0, &'c'),
+                (&1, &'b'),
+                (&2, &'a'),
+                (&2, &'b'),
+            ]
+        );
+    }
+
+    #[test]
+    fn independent_of_insertion_order() {
+        let pairs = (0..100).map(|i| (i % 7, i % 11)).collect::<Vec<_>>();
+        let forward = BiMultiMap::from_iter(pairs.iter().copied());
+        let backward = BiMultiMap::from_iter(pairs.iter().rev().copied());
+
+        assert!(forward.iter_sorted().eq(backward.iter_sorted()));
+        assert!(forward.iter_sorted().is_sorted());
+        assert_eq!(forward.iter_sorted().len(), forward.len());
+    }
+
+    #[test]
+    fn double_ended() {
+        let map = BiMultiMap::from_iter([(0, 'a'), (1, 'a'), (1, 'b')]);
+
+        assert_eq!(
+            map.iter_sorted().rev().collect::<Vec<_>>(),
+            vec![(&1, &'b'), (&1, &'a'), (&0, &'a')]
+        );
+    }
+
+    #[test]
+    fn inverse() {
+        let map = BiMultiMap::from_iter([(0, 'b'), (1, 'a'), (0, 'a')]);
+
+        assert_eq!(
+            map.as_inverse().iter_sorted().collect::<Vec<_>>(),
+            vec![(&'a', &0), (&'a', &1), (&'b', &0)]
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let map = BiMultiMap::<i32, i32>::new();
+
+        assert_eq!(map.iter_sorted().next(), None);
+    }
+}
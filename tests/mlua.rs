@@ -0,0 +1,171 @@
+#![cfg(feature = "mlua")]
+
+use {
+    bimultimap::{
+        BiMultiMap,
+        mlua::{GroupedByLeft, GroupedByRight},
+    },
+    mlua::Lua,
+    std::hash::{BuildHasherDefault, DefaultHasher},
+};
+
+#[test]
+fn grouped_by_left_round_trip() {
+    let lua = Lua::new();
+    let members = BiMultiMap::from_iter(
+        [
+            ("admin", "alice"),
+            ("admin", "bob"),
+            ("user", "bob"),
+        ]
+        .map(|(group, member)| (group.to_string(), member.to_string())),
+    );
+
+    lua.globals()
+        .set("members", GroupedByLeft(members.clone()))
+        .unwrap();
+
+    assert_eq!(lua.load("#members.admin").eval::<usize>().unwrap(), 2);
+    assert_eq!(lua.load("#members.user").eval::<usize>().unwrap(), 1);
+
+    let GroupedByLeft(map) = lua
+        .globals()
+        .get::<GroupedByLeft<String, String>>("members")
+        .unwrap();
+
+    assert_eq!(map, members);
+}
+
+#[test]
+fn grouped_by_right_round_trip() {
+    let lua = Lua::new();
+    let members = BiMultiMap::from_iter(
+        [
+            ("admin", "alice"),
+            ("admin", "bob"),
+            ("user", "bob"),
+        ]
+        .map(|(group, member)| (group.to_string(), member.to_string())),
+    );
+
+    lua.globals()
+        .set("members", GroupedByRight(members.clone()))
+        .unwrap();
+
+    assert_eq!(lua.load("#members.alice").eval::<usize>().unwrap(), 1);
+    assert_eq!(lua.load("#members.bob").eval::<usize>().unwrap(), 2);
+
+    let GroupedByRight(map) = lua
+        .globals()
+        .get::<GroupedByRight<String, String>>("members")
+        .unwrap();
+
+    assert_eq!(map, members);
+}
+
+#[test]
+fn from_lua_table() {
+    let lua = Lua::new();
+    let members = BiMultiMap::from_iter(
+        [
+            ("admin", "alice"),
+            ("admin", "bob"),
+            ("user", "bob"),
+        ]
+        .map(|(group, member)| (group.to_string(), member.to_string())),
+    );
+
+    let GroupedByLeft(by_left) = lua
+        .load(r#"{ admin = {"alice", "bob"}, user = {"bob"} }"#)
+        .eval::<GroupedByLeft<String, String>>()
+        .unwrap();
+    let GroupedByRight(by_right) = lua
+        .load(r#"{ alice = {"admin"}, bob = {"admin", "user"} }"#)
+        .eval::<GroupedByRight<String, String>>()
+        .unwrap();
+
+    assert_eq!(by_left, members);
+    assert_eq!(by_right, members);
+}
+
+#[test]
+fn flat_table_is_lossy() {
+    let lua = Lua::new();
+    let members = BiMultiMap::from_iter(
+        [
+            ("admin", "alice"),
+            ("admin", "bob"),
+            ("user", "bob"),
+        ]
+        .map(|(group, member)| (group.to_string(), member.to_string())),
+    );
+
+    lua.globals().set("members", members).unwrap();
+
+    assert_eq!(
+        lua.globals()
+            .get::<BiMultiMap<String, String>>("members")
+            .unwrap()
+            .len(),
+        2
+    );
+}
+
+#[test]
+fn malformed() {
+    let lua = Lua::new();
+
+    for source in [
+        "0",
+        r#"{ admin = "alice" }"#,
+        r#"{ admin = {{}} }"#,
+        r#"{ admin = {true} }"#,
+        r#"{ admin = {} }"#,
+        r#"{ admin = {"bob", "bob"} }"#,
+        r#"{ [1] = {"bob"}, ["1"] = {"alice"} }"#,
+        r#"{ admin = {"alice", extra = "bob"} }"#,
+        r#"{ admin = {[1] = "alice", [3] = "bob"} }"#,
+    ] {
+        assert!(
+            lua.load(source)
+                .eval::<GroupedByLeft<String, String>>()
+                .is_err(),
+            "{source}"
+        );
+    }
+}
+
+#[test]
+fn malformed_message() {
+    let lua = Lua::new();
+
+    let error = lua
+        .load(r#"{ alice = {} }"#)
+        .eval::<GroupedByRight<String, String>>()
+        .err()
+        .unwrap();
+
+    assert!(
+        error
+            .to_string()
+            .contains("a right value is mapped to an empty sequence of left values"),
+        "{error}"
+    );
+}
+
+#[test]
+fn flat_with_hasher() {
+    type FixedHasher = BuildHasherDefault<DefaultHasher>;
+
+    let lua = Lua::new();
+    let map = lua
+        .load(r#"{ admin = "alice", user = "bob" }"#)
+        .eval::<BiMultiMap<String, String, FixedHasher>>()
+        .unwrap();
+
+    assert_eq!(map.len(), 2);
+
+    lua.globals().set("members", map).unwrap();
+
+    assert_eq!(lua.load("members.user").eval::<String>().unwrap(), "bob");
+}
@@ -0,0 +1,136 @@
+use bimultimap::{BiMultiMap, Set};
+
+fn pairs<'a>(iter: impl Iterator<Item = (&'a char, &'a i32)>) -> Set<(char, i32)> {
+    iter.map(|(&left, &right)| (left, right)).collect()
+}
+
+#[test]
+fn union() {
+    let before = BiMultiMap::from_iter([('a', 0), ('a', 1), ('b', 0)]);
+    let after = BiMultiMap::from_iter([('a', 1), ('b', 0), ('c', 2)]);
+    let expected = Set::from([('a', 0), ('a', 1), ('b', 0), ('c', 2)]);
+
+    assert_eq!(
+        pairs(
+            before
+                .union(&after)
+                .map(|(left, right)| (&**left, &**right))
+        ),
+        expected
+    );
+
+    let union = &before | &after;
+
+    assert_eq!(pairs(union.iter_ref()), expected);
+    union.check_invariants();
+
+    let mut union = before.clone();
+    union.union_with(&after);
+
+    assert_eq!(pairs(union.iter_ref()), expected);
+    union.check_invariants();
+}
+
+#[test]
+fn intersection() {
+    let before = BiMultiMap::from_iter([('a', 0), ('a', 1), ('b', 0)]);
+    let after = BiMultiMap::from_iter([('a', 1), ('b', 0), ('c', 2)]);
+    let expected = Set::from([('a', 1), ('b', 0)]);
+
+    assert_eq!(
+        pairs(
+            before
+                .intersection(&after)
+                .map(|(left, right)| (&**left, &**right))
+        ),
+        expected
+    );
+
+    let intersection = &before & &after;
+
+    assert_eq!(pairs(intersection.iter_ref()), expected);
+    intersection.check_invariants();
+
+    let mut intersection = before.clone();
+    intersection.intersection_with(&after);
+
+    assert_eq!(pairs(intersection.iter_ref()), expected);
+    intersection.check_invariants();
+}
+
+#[test]
+fn difference() {
+    let before = BiMultiMap::from_iter([('a', 0), ('a', 1), ('b', 0)]);
+    let after = BiMultiMap::from_iter([('a', 1), ('b', 0), ('c', 2)]);
+    let expected = Set::from([('a', 0)]);
+
+    assert_eq!(
+        pairs(
+            before
+                .difference(&after)
+                .map(|(left, right)| (&**left, &**right))
+        ),
+        expected
+    );
+
+    let difference = &before - &after;
+
+    assert_eq!(pairs(difference.iter_ref()), expected);
+    difference.check_invariants();
+
+    let mut difference = before.clone();
+    difference.difference_with(&after);
+
+    assert_eq!(pairs(difference.iter_ref()), expected);
+    difference.check_invariants();
+}
+
+#[test]
+fn symmetric_difference() {
+    let before = BiMultiMap::from_iter([('a', 0), ('a', 1), ('b', 0)]);
+    let after = BiMultiMap::from_iter([('a', 1), ('b', 0), ('c', 2)]);
+    let expected = Set::from([('a', 0), ('c', 2)]);
+
+    assert_eq!(
+        pairs(
+            before
+                .symmetric_difference(&after)
+                .map(|(left, right)| (&**left, &**right))
+        ),
+        expected
+    );
+
+    let symmetric_difference = &before ^ &after;
+
+    assert_eq!(pairs(symmetric_difference.iter_ref()), expected);
+    symmetric_difference.check_invariants();
+
+    let mut symmetric_difference = before.clone();
+    symmetric_difference.symmetric_difference_with(&after);
+
+    assert_eq!(pairs(symmetric_difference.iter_ref()), expected);
+    symmetric_difference.check_invariants();
+}
+
+#[test]
+fn with_itself() {
+    let map = BiMultiMap::from_iter([('a', 0), ('a', 1), ('b', 0)]);
+
+    assert_eq!(&map | &map, map);
+    assert_eq!(&map & &map, map);
+    assert!((&map - &map).is_empty());
+    assert!((&map ^ &map).is_empty());
+}
+
+#[test]
+fn shares_values() {
+    let left = BiMultiMap::from_iter([(String::from("a"), 0)]);
+    let right = BiMultiMap::from_iter([(String::from("b"), 0)]);
+
+    let union = &left | &right;
+
+    // `0` is interned once, even though each map has its own allocation of it
+    assert_eq!(union.right_len(), 1);
+    assert_eq!(union.right_degree(&0), 2);
+    union.check_invariants();
+}
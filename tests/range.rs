@@ -0,0 +1,90 @@
+#![cfg(feature = "btreemap")]
+
+use bimultimap::BiMultiMap;
+
+#[test]
+fn range_left() {
+    let map = BiMultiMap::from_iter([
+        (10, 'a'),
+        (20, 'b'),
+        (20, 'c'),
+        (30, 'a'),
+        (40, 'd'),
+    ]);
+
+    assert_eq!(
+        map.range_left(15..35).collect::<Vec<_>>(),
+        vec![(&20, &'b'), (&20, &'c'), (&30, &'a')]
+    );
+    assert_eq!(map.range_left(..=10).collect::<Vec<_>>(), vec![(&10, &'a')]);
+    assert_eq!(map.range_left(41..).next(), None);
+}
+
+#[test]
+fn range_right() {
+    let map = BiMultiMap::from_iter([
+        (10, 'a'),
+        (20, 'b'),
+        (20, 'c'),
+        (30, 'a'),
+        (40, 'd'),
+    ]);
+
+    assert_eq!(
+        map.range_right('a'..'c').collect::<Vec<_>>(),
+        vec![(&10, &'a'), (&30, &'a'), (&20, &'b')]
+    );
+    assert_eq!(map.range_right('x'..).next(), None);
+}
+
+#[test]
+#[should_panic]
+fn range_left_decreasing() {
+    use std::ops::Bound::{Excluded, Included};
+
+    let map = BiMultiMap::from([(2, 'a'), (5, 'b')]);
+
+    let _ = map.range_left((Included(5), Excluded(2)));
+}
+
+#[test]
+#[should_panic]
+fn range_right_empty_excluded() {
+    use std::ops::Bound::Excluded;
+
+    let map = BiMultiMap::from([(2, 'a'), (5, 'b')]);
+
+    let _ = map.range_right((Excluded('a'), Excluded('a')));
+}
+
+#[test]
+fn first_last() {
+    let map = BiMultiMap::from_iter([(20, 'b'), (10, 'c'), (40, 'a')]);
+
+    assert_eq!(map.first_left(), Some(&10));
+    assert_eq!(map.last_left(), Some(&40));
+    assert_eq!(map.first_right(), Some(&'a'));
+    assert_eq!(map.last_right(), Some(&'c'));
+
+    let empty = BiMultiMap::<u8, u8>::new();
+
+    assert_eq!(empty.first_left(), None);
+    assert_eq!(empty.last_right(), None);
+}
+
+#[test]
+fn inverse_range() {
+    let map = BiMultiMap::from_iter([(10, 'a'), (20, 'b'), (30, 'a')]);
+    let inverse = map.as_inverse();
+
+    assert_eq!(
+        inverse.range_left('a'..'b').collect::<Vec<_>>(),
+        vec![(&'a', &10), (&'a', &30)]
+    );
+    assert_eq!(
+        inverse.range_right(15..).collect::<Vec<_>>(),
+        vec![(&'b', &20), (&'a', &30)]
+    );
+    assert_eq!(inverse.first_left(), Some(&'a'));
+    assert_eq!(inverse.last_right(), Some(&30));
+}
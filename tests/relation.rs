@@ -0,0 +1,88 @@
+use bimultimap::{BiMultiMap, Set};
+
+#[test]
+fn compose() {
+    let groups = BiMultiMap::from_iter([
+        ("alice", "admin"),
+        ("alice", "dev"),
+        ("bob", "dev"),
+        ("carol", "guest"),
+    ]);
+    let permissions = BiMultiMap::from_iter([
+        ("admin", "read"),
+        ("admin", "write"),
+        ("dev", "read"),
+        ("dev", "deploy"),
+        ("ops", "reboot"),
+    ]);
+    let users = groups.compose(&permissions);
+
+    assert_eq!(
+        users.iter_ref().map(|(&l, &r)| (l, r)).collect::<Set<_>>(),
+        Set::from([
+            ("alice", "read"),
+            ("alice", "write"),
+            ("alice", "deploy"),
+            ("bob", "read"),
+            ("bob", "deploy"),
+        ])
+    );
+    // `alice` reaches `read` through both groups, but it's a single mapping
+    assert_eq!(users.len(), 5);
+    users.check_invariants();
+}
+
+#[test]
+fn join() {
+    let groups = BiMultiMap::from_iter([
+        ("alice", "admin"),
+        ("alice", "dev"),
+        ("bob", "dev"),
+        ("carol", "guest"),
+    ]);
+    let permissions = BiMultiMap::from_iter([
+        ("admin", "read"),
+        ("admin", "write"),
+        ("dev", "read"),
+        ("dev", "deploy"),
+        ("ops", "reboot"),
+    ]);
+
+    let joined = groups
+        .join(&permissions)
+        .map(|(&l, &m, &r)| (l, m, r))
+        .collect::<Vec<_>>();
+
+    assert_eq!(joined.len(), 6);
+    assert_eq!(
+        joined.iter().copied().collect::<Set<_>>(),
+        Set::from([
+            ("alice", "admin", "read"),
+            ("alice", "admin", "write"),
+            ("alice", "dev", "read"),
+            ("alice", "dev", "deploy"),
+            ("bob", "dev", "read"),
+            ("bob", "dev", "deploy"),
+        ])
+    );
+}
+
+#[test]
+fn join_from_either_side() {
+    let small = BiMultiMap::from_iter([(0, 'a')]);
+    let large = BiMultiMap::from_iter([('a', 0), ('a', 1), ('b', 2), ('c', 3)]);
+
+    // Each side is traversed depending on which one has the fewest middle values
+    assert_eq!(small.join(&large).count(), 2);
+    assert_eq!(large.join(&small).count(), 1);
+    assert_eq!(large.compose(&small).len(), 1);
+}
+
+#[test]
+fn compose_disjoint() {
+    let left = BiMultiMap::from_iter([(0, 'a')]);
+    let right = BiMultiMap::from_iter([('b', 1)]);
+
+    assert!(left.compose(&right).is_empty());
+    assert_eq!(left.join(&right).next(), None);
+}
@@ -1,5 +1,5 @@
 mod remove_right_and_left {
-    use bimultimap::{BiMultiMap, HashSet, Rc};
+    use bimultimap::{BiMultiMap, Rc, Set};
 
     #[test]
     pub fn basic_remove() {
@@ -21,7 +21,7 @@ mod remove_right_and_left {
         map.insert("a", "c");
         map.remove("a", "b");
 
-        assert_eq!(map.get_left(&"a"), Some(&HashSet::from([Rc::new("c")])));
+        assert_eq!(map.get_left(&"a"), Some(&Set::from([Rc::new("c")])));
         assert_eq!(map.len(), 1);
     }
 
@@ -39,7 +39,7 @@ mod remove_right_and_left {
 
         assert_eq!(
             map.get_left(&"a"),
-            Some(&HashSet::from([Rc::new("c"), Rc::new("e")]))
+            Some(&Set::from([Rc::new("c"), Rc::new("e")]))
         );
         assert_eq!(map.len(), 2);
     }
@@ -55,7 +55,7 @@ mod remove_right_and_left {
 
         assert_eq!(
             map.get_left(&"a"),
-            Some(&HashSet::from([Rc::new("b"), Rc::new("c")]))
+            Some(&Set::from([Rc::new("b"), Rc::new("c")]))
         );
         assert_eq!(map.len(), 4);
 
@@ -65,14 +65,14 @@ mod remove_right_and_left {
         assert_eq!(map.get_left(&"a"), None,);
         assert_eq!(
             map.get_left(&"b"),
-            Some(&HashSet::from([Rc::new("b"), Rc::new("c")]))
+            Some(&Set::from([Rc::new("b"), Rc::new("c")]))
         );
         assert_eq!(map.len(), 2);
     }
 }
 
 mod remove_left {
-    use bimultimap::{BiMultiMap, HashSet, Rc};
+    use bimultimap::{BiMultiMap, Rc, Set};
 
     #[test]
     pub fn basic_remove() {
@@ -85,7 +85,7 @@ mod remove_left {
 
         let removed = map.remove_left("a");
 
-        assert_eq!(removed, Some(HashSet::from([Rc::new("b")])));
+        assert_eq!(removed, Some(Set::from([Rc::new("b")])));
 
         assert_eq!(map.len(), 0);
         assert_eq!(map.get_left(&"a"), None);
@@ -101,7 +101,7 @@ mod remove_left {
 
         let removed = map.remove_left("a");
 
-        assert_eq!(removed, Some(HashSet::from([Rc::new("b"), Rc::new("c")])));
+        assert_eq!(removed, Some(Set::from([Rc::new("b"), Rc::new("c")])));
         assert_eq!(map.len(), 0);
         assert_eq!(map.get_left(&"a"), None);
     }
@@ -117,13 +117,13 @@ mod remove_left {
 
         let removed = map.remove_left("a");
 
-        assert_eq!(removed, Some(HashSet::from([Rc::new("b"), Rc::new("c")])));
+        assert_eq!(removed, Some(Set::from([Rc::new("b"), Rc::new("c")])));
 
         assert_eq!(map.get_left(&"a"), None);
 
         assert_eq!(
             map.get_left(&"b"),
-            Some(&HashSet::from([Rc::new("b"), Rc::new("c")]))
+            Some(&Set::from([Rc::new("b"), Rc::new("c")]))
         );
 
         assert_eq!(map.len(), 2);
@@ -141,3 +141,100 @@ mod remove_left {
         assert_eq!(map.len(), 3);
     }
 }
+
+mod bulk {
+    use {
+        bimultimap::{BiMultiMap, Rc, Set},
+        std::collections::BTreeSet,
+    };
+
+    #[test]
+    pub fn clear() {
+        let mut map = BiMultiMap::from_iter([(0, 'a'), (0, 'b'), (1, 'a'), (2, 'c')]);
+
+        map.clear();
+
+        assert!(map.is_empty());
+        assert_eq!(map.get_left(&0), None);
+        assert_eq!(map.get_right(&'a'), None);
+        map.check_invariants();
+    }
+
+    #[test]
+    pub fn drain() {
+        let mut map = BiMultiMap::from_iter([(0, 'a'), (0, 'b'), (1, 'a'), (2, 'c')]);
+
+        let drained = map.drain().map(|(l, r)| (*l, *r)).collect::<BTreeSet<_>>();
+
+        assert_eq!(
+            drained,
+            BTreeSet::from([(0, 'a'), (0, 'b'), (1, 'a'), (2, 'c')])
+        );
+        assert!(map.is_empty());
+        map.check_invariants();
+    }
+
+    #[test]
+    pub fn retain() {
+        let mut map = BiMultiMap::from_iter([(0, 'a'), (0, 'b'), (1, 'a'), (2, 'c')]);
+
+        map.retain(|_, right| *right != 'a');
+
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get_left(&0), Some(&Set::from([Rc::new('b')])));
+        assert_eq!(map.get_left(&1), None);
+        assert_eq!(map.get_right(&'a'), None);
+        map.check_invariants();
+    }
+
+    #[test]
+    pub fn extract_if() {
+        let mut map = BiMultiMap::from_iter([(0, 'a'), (0, 'b'), (1, 'a'), (2, 'c')]);
+
+        let extracted = map
+            .extract_if(|left, _| *left == 0)
+            .map(|(l, r)| (*l, *r))
+            .collect::<BTreeSet<_>>();
+
+        assert_eq!(extracted, BTreeSet::from([(0, 'a'), (0, 'b')]));
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get_right(&'a'), Some(&Set::from([Rc::new(1)])));
+        assert_eq!(map.get_right(&'b'), None);
+        map.check_invariants();
+    }
+
+    #[test]
+    pub fn extract_if_not_consumed() {
+        let mut map = BiMultiMap::from_iter([(0, 'a'), (0, 'b'), (1, 'a'), (2, 'c')]);
+
+        let _ = map.extract_if(|_, _| true);
+
+        assert!(map.is_empty());
+        map.check_invariants();
+    }
+
+    #[test]
+    pub fn retain_left() {
+        let mut map = BiMultiMap::from_iter([(0, 'a'), (0, 'b'), (1, 'a'), (2, 'c')]);
+
+        map.retain_left(|_, right_set| right_set.len() > 1);
+
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.left_len(), 1);
+        assert_eq!(map.get_right(&'a'), Some(&Set::from([Rc::new(0)])));
+        assert_eq!(map.get_right(&'c'), None);
+        map.check_invariants();
+    }
+
+    #[test]
+    pub fn retain_right() {
+        let mut map = BiMultiMap::from_iter([(0, 'a'), (0, 'b'), (1, 'a'), (2, 'c')]);
+
+        map.retain_right(|right, left_set| *right != 'c' && !left_set.contains(&1));
+
+        assert_eq!(map.len(), 1);
+        assert_eq!(map.get_left(&0), Some(&Set::from([Rc::new('b')])));
+        assert_eq!(map.get_left(&2), None);
+        map.check_invariants();
+    }
+}
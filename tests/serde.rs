@@ -58,3 +58,566 @@ mod ser {
         assert_eq!(str, result.unwrap())
     }
 }
+
+#[cfg(feature = "serde")]
+mod grouped {
+    use {
+        bimultimap::BiMultiMap,
+        serde::{Deserialize, Serialize},
+    };
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Groups {
+        #[serde(with = "bimultimap::serde::grouped")]
+        members: BiMultiMap<String, u8>,
+    }
+
+    #[test]
+    fn serialize() {
+        let groups = Groups {
+            members: BiMultiMap::from_iter([
+                (String::from("a"), 0),
+                (String::from("a"), 1),
+                (String::from("b"), 1),
+            ]),
+        };
+        let value = serde_json::to_value(groups).unwrap();
+
+        assert_eq!(value["members"]["a"].as_array().unwrap().len(), 2);
+        assert_eq!(value["members"]["b"], serde_json::json!([1]));
+    }
+
+    #[test]
+    fn round_trip() {
+        let groups = Groups {
+            members: BiMultiMap::from_iter([
+                (String::from("a"), 0),
+                (String::from("a"), 1),
+                (String::from("b"), 1),
+            ]),
+        };
+        let json = serde_json::to_string(&groups).unwrap();
+
+        assert_eq!(serde_json::from_str::<Groups>(&json).unwrap(), groups);
+    }
+
+    #[test]
+    fn round_trip_through_value() {
+        let groups = Groups {
+            members: BiMultiMap::from_iter([
+                (String::from("a"), 0),
+                (String::from("a"), 1),
+                (String::from("b"), 1),
+            ]),
+        };
+        // `serde_json::Value` collapses repeated keys, which the grouped form doesn't have
+        let value = serde_json::to_value(&groups).unwrap();
+
+        assert_eq!(serde_json::from_value::<Groups>(value).unwrap(), groups);
+    }
+
+    #[test]
+    fn errors() {
+        for (json, message) in [
+            (
+                r#"{"members":{"a":[0],"a":[1]}}"#,
+                "the left value of the group at index 1 appears in an earlier group",
+            ),
+            (
+                r#"{"members":{"a":[0],"b":[]}}"#,
+                "the left value of the group at index 1 is mapped to an empty sequence of right \
+                 values",
+            ),
+            (
+                r#"{"members":{"a":[0,0]}}"#,
+                "the left value of the group at index 0 is mapped to the same right value twice",
+            ),
+            (
+                r#"{"members":{"a":[0,"x"]}}"#,
+                "invalid type: string \"x\", expected u8 as the right value of the group at \
+                 index 0",
+            ),
+        ] {
+            let error = serde_json::from_str::<Groups>(json).unwrap_err();
+
+            assert!(error.to_string().starts_with(message), "{error}");
+        }
+    }
+
+    #[test]
+    fn malformed() {
+        for json in [
+            r#"{"members":{"a":[]}}"#,
+            r#"{"members":{"a":[0, 0]}}"#,
+            r#"{"members":{"a":[0], "a":[1]}}"#,
+            r#"{"members":{"a":0}}"#,
+            r#"{"members":[["a", 0]]}"#,
+        ] {
+            assert!(serde_json::from_str::<Groups>(json).is_err(), "{json}");
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+mod pairs {
+    use {
+        bimultimap::BiMultiMap,
+        serde::{Deserialize, Serialize},
+    };
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Groups {
+        #[serde(with = "bimultimap::serde::pairs")]
+        members: BiMultiMap<String, u8>,
+    }
+
+    #[test]
+    fn serialize() {
+        let groups = Groups {
+            members: BiMultiMap::from_iter([(String::from("a"), 0)]),
+        };
+
+        assert_eq!(
+            serde_json::to_string(&groups).unwrap(),
+            r#"{"members":[["a",0]]}"#
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let groups = Groups {
+            members: BiMultiMap::from_iter([
+                (String::from("a"), 0),
+                (String::from("a"), 1),
+                (String::from("b"), 1),
+            ]),
+        };
+        let json = serde_json::to_string(&groups).unwrap();
+
+        assert_eq!(serde_json::from_str::<Groups>(&json).unwrap(), groups);
+    }
+
+    #[test]
+    fn malformed() {
+        for json in [
+            r#"{"members":[["a", 0], ["a", 0]]}"#,
+            r#"{"members":[["a"]]}"#,
+            r#"{"members":[["a", 0, 1]]}"#,
+            r#"{"members":{"a":[0]}}"#,
+        ] {
+            assert!(serde_json::from_str::<Groups>(json).is_err(), "{json}");
+        }
+    }
+
+    #[test]
+    fn duplicate_message() {
+        let error =
+            serde_json::from_str::<Groups>(r#"{"members":[["a", 0], ["a", 0]]}"#).unwrap_err();
+
+        assert!(
+            error.to_string().starts_with(
+                "the left and right values of the mapping at index 1 are already mapped to each \
+                 other"
+            ),
+            "{error}"
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+mod two_maps {
+    use {
+        bimultimap::BiMultiMap,
+        serde::{Deserialize, Serialize},
+    };
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Groups {
+        #[serde(with = "bimultimap::serde::two_maps")]
+        members: BiMultiMap<String, String>,
+    }
+
+    #[test]
+    fn round_trip() {
+        let groups = Groups {
+            members: BiMultiMap::from_iter([
+                (String::from("admin"), String::from("alice")),
+                (String::from("admin"), String::from("bob")),
+                (String::from("dev"), String::from("bob")),
+            ]),
+        };
+        let value = serde_json::to_value(&groups).unwrap();
+
+        assert_eq!(
+            value["members"]["right"]["bob"].as_array().unwrap().len(),
+            2
+        );
+        assert_eq!(serde_json::from_value::<Groups>(value).unwrap(), groups);
+    }
+
+    #[test]
+    fn malformed() {
+        for json in [
+            r#"{"members":{"left":{"a":["b"]},"right":{"b":["c"]}}}"#,
+            r#"{"members":{"left":{"a":["b"]},"right":{}}}"#,
+            r#"{"members":{"left":{"a":["b"]}}}"#,
+            r#"{"members":{"left":{"a":[]},"right":{}}}"#,
+            r#"{"members":{"left":{},"right":{},"left":{}}}"#,
+            r#"{"members":{"left":{},"right":{},"up":{}}}"#,
+        ] {
+            assert!(serde_json::from_str::<Groups>(json).is_err(), "{json}");
+        }
+
+        assert!(serde_json::from_str::<Groups>(r#"{"members":{"left":{},"right":{}}}"#).is_ok());
+    }
+    #[test]
+    fn right_side_errors() {
+        let error = serde_json::from_str::<Groups>(
+            r#"{"members":{"left":{"a":["b"]},"right":{"b":["a","a"]}}}"#,
+        )
+        .unwrap_err();
+
+        assert!(
+            error.to_string().starts_with(
+                "the right value of the group at index 0 is mapped to the same left value twice"
+            ),
+            "{error}"
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+mod composite_keys {
+    use {
+        bimultimap::BiMultiMap,
+        serde::{Deserialize, Serialize, de::DeserializeOwned},
+        std::fmt::Debug,
+    };
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Clone)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    type Map = BiMultiMap<Point, (u8, String)>;
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Flat(Map);
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Grouped(#[serde(with = "bimultimap::serde::grouped")] Map);
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct GroupedSeq(#[serde(with = "bimultimap::serde::grouped_seq")] Map);
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Pairs(#[serde(with = "bimultimap::serde::pairs")] Map);
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct TwoMaps(#[serde(with = "bimultimap::serde::two_maps")] Map);
+
+    fn map() -> Map {
+        BiMultiMap::from_iter([
+            (Point { x: 0, y: 0 }, (0, String::from("origin"))),
+            (Point { x: 0, y: 0 }, (1, String::from("center"))),
+            (Point { x: 1, y: 2 }, (1, String::from("center"))),
+        ])
+    }
+
+    fn json<T: Serialize + DeserializeOwned + PartialEq + Debug>(value: T) {
+        let json = serde_json::to_string(&value).unwrap();
+
+        assert_eq!(serde_json::from_str::<T>(&json).unwrap(), value);
+    }
+
+    fn bincode<T: Serialize + DeserializeOwned + PartialEq + Debug>(value: T) {
+        let bytes = bincode::serialize(&value).unwrap();
+
+        assert_eq!(bincode::deserialize::<T>(&bytes).unwrap(), value);
+    }
+
+    fn postcard<T: Serialize + DeserializeOwned + PartialEq + Debug>(value: T) {
+        let bytes = postcard::to_allocvec(&value).unwrap();
+
+        assert_eq!(postcard::from_bytes::<T>(&bytes).unwrap(), value);
+    }
+
+    #[test]
+    fn json_needs_string_keys() {
+        assert!(serde_json::to_string(&Flat(map())).is_err());
+        assert!(serde_json::to_string(&Grouped(map())).is_err());
+        assert!(serde_json::to_string(&TwoMaps(map())).is_err());
+    }
+
+    #[test]
+    fn json_sequences() {
+        json(GroupedSeq(map()));
+        json(Pairs(map()));
+    }
+
+    #[test]
+    fn bincode_all() {
+        bincode(Flat(map()));
+        bincode(Grouped(map()));
+        bincode(GroupedSeq(map()));
+        bincode(Pairs(map()));
+        bincode(TwoMaps(map()));
+    }
+
+    #[test]
+    fn postcard_all() {
+        postcard(Flat(map()));
+        postcard(Grouped(map()));
+        postcard(GroupedSeq(map()));
+        postcard(Pairs(map()));
+        postcard(TwoMaps(map()));
+    }
+
+    #[test]
+    fn grouped_seq_malformed() {
+        for json in [
+            r#"[[{"x":0,"y":0},[]]]"#,
+            r#"[[{"x":0,"y":0},[[0,"a"],[0,"a"]]]]"#,
+            r#"[[{"x":0,"y":0},[[0,"a"]]],[{"x":0,"y":0},[[1,"b"]]]]"#,
+        ] {
+            assert!(serde_json::from_str::<GroupedSeq>(json).is_err(), "{json}");
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+mod sorted {
+    use {
+        bimultimap::BiMultiMap,
+        serde::{Deserialize, Serialize},
+    };
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Sorted {
+        #[serde(with = "bimultimap::serde::sorted")]
+        flat:        BiMultiMap<String, u8>,
+        #[serde(with = "bimultimap::serde::sorted::grouped")]
+        grouped:     BiMultiMap<String, u8>,
+        #[serde(with = "bimultimap::serde::sorted::grouped_seq")]
+        grouped_seq: BiMultiMap<String, u8>,
+        #[serde(with = "bimultimap::serde::sorted::pairs")]
+        pairs:       BiMultiMap<String, u8>,
+        #[serde(with = "bimultimap::serde::sorted::two_maps")]
+        two_maps:    BiMultiMap<String, u8>,
+    }
+
+    fn sorted(pairs: &[(&str, u8)]) -> Sorted {
+        let map = BiMultiMap::from_iter(pairs.iter().map(|&(left, right)| (left.into(), right)));
+
+        Sorted {
+            flat:        map.clone(),
+            grouped:     map.clone(),
+            grouped_seq: map.clone(),
+            pairs:       map.clone(),
+            two_maps:    map,
+        }
+    }
+
+    const PAIRS: &[(&str, u8)] = &[
+        ("user", 2),
+        ("admin", 1),
+        ("user", 1),
+        ("admin", 0),
+    ];
+
+    #[test]
+    fn output() {
+        assert_eq!(
+            serde_json::to_value(sorted(PAIRS)).unwrap(),
+            serde_json::json!({
+                "flat": {"admin": 1, "user": 2},
+                "grouped": {"admin": [0, 1], "user": [1, 2]},
+                "grouped_seq": [["admin", [0, 1]], ["user", [1, 2]]],
+                "pairs": [["admin", 0], ["admin", 1], ["user", 1], ["user", 2]],
+                "two_maps": {
+                    "left": {"admin": [0, 1], "user": [1, 2]},
+                    "right": {"0": ["admin"], "1": ["admin", "user"], "2": ["user"]},
+                },
+            })
+        );
+
+        assert_eq!(
+            serde_json::to_string(&sorted(PAIRS)).unwrap(),
+            concat!(
+                r#"{"flat":{"admin":0,"admin":1,"user":1,"user":2},"#,
+                r#""grouped":{"admin":[0,1],"user":[1,2]},"#,
+                r#""grouped_seq":[["admin",[0,1]],["user",[1,2]]],"#,
+                r#""pairs":[["admin",0],["admin",1],["user",1],["user",2]],"#,
+                r#""two_maps":{"left":{"admin":[0,1],"user":[1,2]},"#,
+                r#""right":{"0":["admin"],"1":["admin","user"],"2":["user"]}}}"#,
+            )
+        );
+    }
+
+    #[test]
+    fn canonical() {
+        let reversed = PAIRS.iter().rev().copied().collect::<Vec<_>>();
+        let expected = serde_json::to_string(&sorted(PAIRS)).unwrap();
+
+        for _ in 0..10 {
+            assert_eq!(serde_json::to_string(&sorted(&reversed)).unwrap(), expected);
+            assert_eq!(
+                bincode::serialize(&sorted(&reversed)).unwrap(),
+                bincode::serialize(&sorted(PAIRS)).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let value = sorted(PAIRS);
+        let json = serde_json::to_string(&value).unwrap();
+
+        assert_eq!(serde_json::from_str::<Sorted>(&json).unwrap(), value);
+    }
+}
+
+#[cfg(feature = "serde")]
+mod default_representation {
+    use {
+        bimultimap::BiMultiMap,
+        serde::{Deserialize, Serialize},
+    };
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Strict {
+        #[serde(with = "bimultimap::serde::strict")]
+        members: BiMultiMap<String, u8>,
+    }
+
+    #[test]
+    fn sequence_of_pairs() {
+        let map: BiMultiMap<String, u8> =
+            serde_json::from_str(r#"[["admin", 0], ["admin", 1], ["user", 1]]"#).unwrap();
+
+        assert_eq!(
+            map,
+            BiMultiMap::from_iter([
+                (String::from("admin"), 0),
+                (String::from("admin"), 1),
+                (String::from("user"), 1),
+            ])
+        );
+    }
+
+    #[test]
+    fn duplicates_kept_once() {
+        let map: BiMultiMap<String, u8> =
+            serde_json::from_str(r#"{"admin": 0, "admin": 0}"#).unwrap();
+
+        assert_eq!(map.len(), 1);
+    }
+
+    #[test]
+    fn strict_rejects_duplicates() {
+        let error = serde_json::from_str::<Strict>(
+            r#"{
+                "members": [["admin", 0], ["user", 0], ["admin", 0]]
+            }"#,
+        )
+        .unwrap_err();
+
+        assert!(
+            error
+                .to_string()
+                .starts_with("the left and right values of the mapping at index 2 are already")
+        );
+        assert_eq!(error.line(), 2);
+    }
+
+    #[test]
+    fn strict_round_trip() {
+        let strict = Strict {
+            members: BiMultiMap::from_iter([
+                (String::from("admin"), 0),
+                (String::from("user"), 0),
+            ]),
+        };
+        let json = serde_json::to_string(&strict).unwrap();
+
+        assert_eq!(serde_json::from_str::<Strict>(&json).unwrap(), strict);
+        assert_eq!(
+            bincode::deserialize::<Strict>(&bincode::serialize(&strict).unwrap()).unwrap(),
+            strict
+        );
+    }
+
+    #[test]
+    fn expecting() {
+        let error = serde_json::from_str::<BiMultiMap<String, u8>>("0").unwrap_err();
+
+        assert!(error.to_string().contains(
+            "expected a map from left values to right values, or a sequence of [left, right] pairs"
+        ));
+    }
+
+    #[test]
+    fn side_and_index() {
+        for (json, message) in [
+            (
+                r#"{"admin": 0, "user": "zero"}"#,
+                "invalid type: string \"zero\", expected u8 as the right value of the mapping at \
+                 index 1",
+            ),
+            (
+                r#"[["admin", 0], [1, 0]]"#,
+                "invalid type: integer `1`, expected a string as the left value of the mapping at \
+                 index 1",
+            ),
+            (
+                r#"[["admin"]]"#,
+                "invalid length 1, expected a pair at index 0",
+            ),
+        ] {
+            let error = serde_json::from_str::<BiMultiMap<String, u8>>(json).unwrap_err();
+
+            assert!(error.to_string().starts_with(message), "{error}");
+        }
+    }
+
+    #[test]
+    fn invalid_value_location() {
+        let error =
+            serde_json::from_str::<BiMultiMap<String, u8>>("{\n\"admin\": \"zero\"}").unwrap_err();
+
+        assert!(error.is_data());
+        assert_eq!(error.line(), 2);
+    }
+
+    #[test]
+    fn borrowed() {
+        let json = String::from(r#"{"admin": "alice", "admin": "bob", "user": "bob"}"#);
+        let map: BiMultiMap<&str, &str> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(
+            map,
+            BiMultiMap::from_iter([
+                ("admin", "alice"),
+                ("admin", "bob"),
+                ("user", "bob")
+            ])
+        );
+
+        for (left, right) in map.iter_ref() {
+            assert!(json.as_bytes().as_ptr_range().contains(&left.as_ptr()));
+            assert!(json.as_bytes().as_ptr_range().contains(&right.as_ptr()));
+        }
+    }
+
+    #[test]
+    fn borrowed_pairs() {
+        let json = String::from(r#"[["admin", "alice"], ["user", "bob"]]"#);
+        let map: BiMultiMap<&str, &str> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(
+            map,
+            BiMultiMap::from_iter([("admin", "alice"), ("user", "bob")])
+        );
+    }
+}
@@ -1,5 +1,5 @@
 mod set_left {
-    use bimultimap::{BiMultiMap, HashSet, Rc};
+    use bimultimap::{BiMultiMap, Rc, Set, SetDiff};
 
     #[test]
     pub fn basic() {
@@ -9,13 +9,14 @@ mod set_left {
 
         assert_eq!(
             map.get_left(&0),
-            Some(&HashSet::from([Rc::new(1), Rc::new(2), Rc::new(3),]))
+            Some(&Set::from([Rc::new(1), Rc::new(2), Rc::new(3),]))
         );
 
         assert_eq!(map.get_right(&0), None);
-        assert_eq!(map.get_right(&1), Some(&HashSet::from([Rc::new(0)])));
-        assert_eq!(map.get_right(&2), Some(&HashSet::from([Rc::new(0)])));
-        assert_eq!(map.get_right(&3), Some(&HashSet::from([Rc::new(0)])));
+        assert_eq!(map.get_right(&1), Some(&Set::from([Rc::new(0)])));
+        assert_eq!(map.get_right(&2), Some(&Set::from([Rc::new(0)])));
+        assert_eq!(map.get_right(&3), Some(&Set::from([Rc::new(0)])));
+        assert_eq!(map.len(), 3);
     }
 
     #[test]
@@ -27,14 +28,15 @@ mod set_left {
 
         assert_eq!(
             map.get_left(&0),
-            Some(&HashSet::from([Rc::new(1), Rc::new(2), Rc::new(3),]))
+            Some(&Set::from([Rc::new(1), Rc::new(2), Rc::new(3),]))
         );
 
         assert_eq!(map.get_right(&0), None);
-        assert_eq!(map.get_right(&1), Some(&HashSet::from([Rc::new(0)])));
-        assert_eq!(map.get_right(&2), Some(&HashSet::from([Rc::new(0)])));
-        assert_eq!(map.get_right(&3), Some(&HashSet::from([Rc::new(0)])));
+        assert_eq!(map.get_right(&1), Some(&Set::from([Rc::new(0)])));
+        assert_eq!(map.get_right(&2), Some(&Set::from([Rc::new(0)])));
+        assert_eq!(map.get_right(&3), Some(&Set::from([Rc::new(0)])));
         assert_eq!(map.get_right(&4), None);
+        assert_eq!(map.len(), 3);
     }
 
     #[test]
@@ -47,20 +49,80 @@ mod set_left {
         map.insert(1, 3);
         map.set_left(0.into(), [4].into());
 
-        assert_eq!(map.get_left(&0), Some(&HashSet::from([Rc::new(4)])));
+        assert_eq!(map.get_left(&0), Some(&Set::from([Rc::new(4)])));
         assert_eq!(
             map.get_left(&1),
-            Some(&HashSet::from([Rc::new(2), Rc::new(3),]))
+            Some(&Set::from([Rc::new(2), Rc::new(3),]))
         );
 
-        assert_eq!(map.get_right(&2), Some(&HashSet::from([Rc::new(1)])));
-        assert_eq!(map.get_right(&3), Some(&HashSet::from([Rc::new(1)])));
-        assert_eq!(map.get_right(&4), Some(&HashSet::from([Rc::new(0)])));
+        assert_eq!(map.get_right(&2), Some(&Set::from([Rc::new(1)])));
+        assert_eq!(map.get_right(&3), Some(&Set::from([Rc::new(1)])));
+        assert_eq!(map.get_right(&4), Some(&Set::from([Rc::new(0)])));
+        assert_eq!(map.len(), 3);
+    }
+
+    #[test]
+    pub fn empty_set() {
+        let mut map = BiMultiMap::<i32, i32>::new();
+
+        map.insert(0, 1);
+        map.set_left(0.into(), Set::new());
+        map.set_left(2.into(), Set::new());
+
+        assert_eq!(map.get_left(&0), None);
+        assert_eq!(map.get_left(&2), None);
+        assert_eq!(map.get_right(&1), None);
+        assert_eq!(map.len(), 0);
+        map.check_invariants();
+    }
+
+    #[test]
+    pub fn returned_diff() {
+        let mut map = BiMultiMap::<i32, i32>::new();
+
+        let diff = map.set_left(0.into(), [1, 2].into());
+
+        assert_eq!(
+            diff,
+            SetDiff {
+                added:   Set::from([Rc::new(1), Rc::new(2)]),
+                removed: Set::new(),
+            }
+        );
+
+        let diff = map.set_left(0.into(), [2, 3].into());
+
+        assert_eq!(
+            diff,
+            SetDiff {
+                added:   Set::from([Rc::new(3)]),
+                removed: Set::from([Rc::new(1)]),
+            }
+        );
+    }
+
+    #[test]
+    pub fn shared_allocation() {
+        let mut map = BiMultiMap::<i32, i32>::new();
+
+        map.insert(1, 1);
+        map.set_left(0.into(), [1, 2].into());
+        map.set_left(0.into(), [1, 2, 3].into());
+
+        let one = map.right_values().find(|value| ***value == 1).unwrap();
+
+        assert_eq!(Rc::strong_count(one), 3);
+        assert!(
+            map.get_left(&0)
+                .unwrap()
+                .iter()
+                .any(|value| Rc::ptr_eq(value, one))
+        );
     }
 }
 
 mod set_right {
-    use bimultimap::{BiMultiMap, HashSet, Rc};
+    use bimultimap::{BiMultiMap, Rc, Set, SetDiff};
 
     #[test]
     pub fn basic() {
@@ -70,13 +132,14 @@ mod set_right {
 
         assert_eq!(
             map.get_right(&0),
-            Some(&HashSet::from([Rc::new(1), Rc::new(2), Rc::new(3),]))
+            Some(&Set::from([Rc::new(1), Rc::new(2), Rc::new(3),]))
         );
 
         assert_eq!(map.get_left(&0), None);
-        assert_eq!(map.get_left(&1), Some(&HashSet::from([Rc::new(0)])));
-        assert_eq!(map.get_left(&2), Some(&HashSet::from([Rc::new(0)])));
-        assert_eq!(map.get_left(&3), Some(&HashSet::from([Rc::new(0)])));
+        assert_eq!(map.get_left(&1), Some(&Set::from([Rc::new(0)])));
+        assert_eq!(map.get_left(&2), Some(&Set::from([Rc::new(0)])));
+        assert_eq!(map.get_left(&3), Some(&Set::from([Rc::new(0)])));
+        assert_eq!(map.len(), 3);
     }
 
     #[test]
@@ -88,14 +151,15 @@ mod set_right {
 
         assert_eq!(
             map.get_right(&0),
-            Some(&HashSet::from([Rc::new(1), Rc::new(2), Rc::new(3),]))
+            Some(&Set::from([Rc::new(1), Rc::new(2), Rc::new(3),]))
         );
 
         assert_eq!(map.get_left(&0), None);
-        assert_eq!(map.get_left(&1), Some(&HashSet::from([Rc::new(0)])));
-        assert_eq!(map.get_left(&2), Some(&HashSet::from([Rc::new(0)])));
-        assert_eq!(map.get_left(&3), Some(&HashSet::from([Rc::new(0)])));
+        assert_eq!(map.get_left(&1), Some(&Set::from([Rc::new(0)])));
+        assert_eq!(map.get_left(&2), Some(&Set::from([Rc::new(0)])));
+        assert_eq!(map.get_left(&3), Some(&Set::from([Rc::new(0)])));
         assert_eq!(map.get_left(&4), None);
+        assert_eq!(map.len(), 3);
     }
 
     #[test]
@@ -108,14 +172,74 @@ mod set_right {
         map.insert(3, 1);
         map.set_right(0.into(), [4].into());
 
-        assert_eq!(map.get_right(&0), Some(&HashSet::from([Rc::new(4)])));
+        assert_eq!(map.get_right(&0), Some(&Set::from([Rc::new(4)])));
         assert_eq!(
             map.get_right(&1),
-            Some(&HashSet::from([Rc::new(2), Rc::new(3),]))
+            Some(&Set::from([Rc::new(2), Rc::new(3),]))
         );
 
-        assert_eq!(map.get_left(&2), Some(&HashSet::from([Rc::new(1)])));
-        assert_eq!(map.get_left(&3), Some(&HashSet::from([Rc::new(1)])));
-        assert_eq!(map.get_left(&4), Some(&HashSet::from([Rc::new(0)])));
+        assert_eq!(map.get_left(&2), Some(&Set::from([Rc::new(1)])));
+        assert_eq!(map.get_left(&3), Some(&Set::from([Rc::new(1)])));
+        assert_eq!(map.get_left(&4), Some(&Set::from([Rc::new(0)])));
+        assert_eq!(map.len(), 3);
+    }
+
+    #[test]
+    pub fn empty_set() {
+        let mut map = BiMultiMap::<i32, i32>::new();
+
+        map.insert(1, 0);
+        map.set_right(0.into(), Set::new());
+        map.set_right(2.into(), Set::new());
+
+        assert_eq!(map.get_right(&0), None);
+        assert_eq!(map.get_right(&2), None);
+        assert_eq!(map.get_left(&1), None);
+        assert_eq!(map.len(), 0);
+        map.check_invariants();
+    }
+
+    #[test]
+    pub fn returned_diff() {
+        let mut map = BiMultiMap::<i32, i32>::new();
+
+        let diff = map.set_right(0.into(), [1, 2].into());
+
+        assert_eq!(
+            diff,
+            SetDiff {
+                added:   Set::from([Rc::new(1), Rc::new(2)]),
+                removed: Set::new(),
+            }
+        );
+
+        let diff = map.set_right(0.into(), [2, 3].into());
+
+        assert_eq!(
+            diff,
+            SetDiff {
+                added:   Set::from([Rc::new(3)]),
+                removed: Set::from([Rc::new(1)]),
+            }
+        );
+    }
+
+    #[test]
+    pub fn shared_allocation() {
+        let mut map = BiMultiMap::<i32, i32>::new();
+
+        map.insert(1, 1);
+        map.set_right(0.into(), [1, 2].into());
+        map.set_right(0.into(), [1, 2, 3].into());
+
+        let one = map.left_values().find(|value| ***value == 1).unwrap();
+
+        assert_eq!(Rc::strong_count(one), 3);
+        assert!(
+            map.get_right(&0)
+                .unwrap()
+                .iter()
+                .any(|value| Rc::ptr_eq(value, one))
+        );
     }
 }
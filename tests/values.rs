@@ -0,0 +1,145 @@
+use {
+    bimultimap::{BiMultiMapWithValues, IterEdges, Map, Rc},
+    std::collections::BTreeSet,
+};
+
+#[test]
+fn insert_and_get() {
+    let mut map = BiMultiMapWithValues::from_iter([
+        ("a", "b", 1),
+        ("a", "c", 2),
+        ("d", "c", 3),
+    ]);
+
+    assert_eq!(map.len(), 3);
+    assert_eq!(map.get_edge("a", "c"), Some(&2));
+    assert_eq!(map.get_edge("d", "b"), None);
+    assert_eq!(map.insert("a", "c", 4), Some(2));
+    assert_eq!(map.get_edge("a", "c"), Some(&4));
+    assert_eq!(map.len(), 3);
+    map.check_invariants();
+}
+
+#[test]
+fn get_edge_mut() {
+    let mut map = BiMultiMapWithValues::from_iter([
+        ("a", "b", 1),
+        ("a", "c", 2),
+        ("d", "c", 3),
+    ]);
+
+    *map.get_edge_mut("d", "c").unwrap() += 10;
+
+    assert_eq!(map.get_edge("d", "c"), Some(&13));
+    assert_eq!(map.get_edge_mut("d", "b"), None);
+}
+
+#[test]
+fn iter_edges() {
+    let map = BiMultiMapWithValues::from_iter([
+        ("a", "b", 1),
+        ("a", "c", 2),
+        ("d", "c", 3),
+    ]);
+
+    assert_eq!(
+        map.iter_edges().collect::<BTreeSet<_>>(),
+        BTreeSet::from([
+            (&"a", &"b", &1),
+            (&"a", &"c", &2),
+            (&"d", &"c", &3)
+        ])
+    );
+}
+
+#[test]
+fn iter_edges_type() {
+    struct Holder<'a> {
+        edges: IterEdges<'a, &'static str, &'static str, u32>,
+    }
+
+    let map = BiMultiMapWithValues::from_iter([
+        ("a", "b", 1),
+        ("a", "c", 2),
+        ("d", "c", 3),
+    ]);
+    let mut holder = Holder {
+        edges: map.iter_edges(),
+    };
+
+    assert_eq!(holder.edges.len(), 3);
+    holder.edges.next();
+
+    let cloned = holder.edges.clone();
+
+    assert_eq!(cloned.len(), 2);
+    assert_eq!(
+        cloned.collect::<BTreeSet<_>>(),
+        holder.edges.by_ref().collect::<BTreeSet<_>>()
+    );
+    assert_eq!(holder.edges.next(), None);
+}
+
+#[test]
+fn read_api() {
+    let map = BiMultiMapWithValues::from_iter([
+        ("a", "b", 1),
+        ("a", "c", 2),
+        ("d", "c", 3),
+    ]);
+
+    assert_eq!(map.right_degree("c"), 2);
+    assert_eq!(map.left_degree("a"), 2);
+    assert!(map.contains("a", "b"));
+    assert!(!map.contains("d", "b"));
+    assert!(map.contains_left("d") && !map.contains_right("d"));
+    assert_eq!((map.left_len(), map.right_len()), (2, 2));
+}
+
+#[test]
+fn into_map() {
+    let map = BiMultiMapWithValues::from_iter([
+        ("a", "b", 1),
+        ("a", "c", 2),
+        ("d", "c", 3),
+    ])
+    .into_map();
+
+    map.check_invariants();
+    assert_eq!(map.len(), 3);
+    assert_eq!(map.right_degree("c"), 2);
+}
+
+#[test]
+fn remove() {
+    let mut map = BiMultiMapWithValues::from_iter([
+        ("a", "b", 1),
+        ("a", "c", 2),
+        ("d", "c", 3),
+    ]);
+
+    assert_eq!(map.remove("a", "c"), Some(2));
+    assert_eq!(map.remove("a", "c"), None);
+    assert_eq!(map.len(), 2);
+    map.check_invariants();
+}
+
+#[test]
+fn remove_left_and_right() {
+    let mut map = BiMultiMapWithValues::from_iter([
+        ("a", "b", 1),
+        ("a", "c", 2),
+        ("d", "c", 3),
+    ]);
+
+    assert_eq!(
+        map.remove_right("c"),
+        Some(Map::from([(Rc::new("a"), 2), (Rc::new("d"), 3)]))
+    );
+    map.check_invariants();
+
+    assert_eq!(map.remove_left("a"), Some(Map::from([(Rc::new("b"), 1)])));
+    assert_eq!(map.remove_left("a"), None);
+    assert!(map.is_empty());
+    map.check_invariants();
+}